@@ -1,7 +1,177 @@
-use clap::Parser;
+use clap::builder::PossibleValuesParser;
+use clap::{Parser, ValueEnum};
+use pitchconv::pitch::{self, Frequency, NotationRegistry};
 
 #[derive(Debug, Parser)]
 #[command(author, version, about, long_about = None)]
 pub struct Args {
     pub pitch: Option<String>,
+
+    /// Input format [default: detected from the input]
+    #[arg(long, value_parser = notation_names())]
+    pub from: Option<String>,
+
+    /// Output format [default: alternative for scientific input, scientific otherwise]
+    #[arg(long, value_parser = notation_names())]
+    pub to: Option<String>,
+
+    /// Octave number of middle C in the input
+    #[arg(long, value_enum, default_value_t = MiddleC::C4)]
+    pub from_middle_c: MiddleC,
+
+    /// Octave number of middle C in the output
+    #[arg(long, value_enum, default_value_t = MiddleC::C4)]
+    pub to_middle_c: MiddleC,
+
+    /// First note of each octave word in alternative input
+    #[arg(long, value_enum, default_value_t = OctaveBoundary::A)]
+    pub from_octave_boundary: OctaveBoundary,
+
+    /// First note of each octave word in alternative output
+    #[arg(long, value_enum, default_value_t = OctaveBoundary::A)]
+    pub to_octave_boundary: OctaveBoundary,
+
+    /// Syllable spelling for solfège output; Spanish is spelled like Italian
+    #[arg(long, value_enum, default_value_t = SolfegeVariant::Italian)]
+    pub solfege_variant: SolfegeVariant,
+
+    /// Octave marks for Helmholtz output
+    #[arg(long, value_enum, default_value_t = HelmholtzStyle::Marks)]
+    pub helmholtz_style: HelmholtzStyle,
+
+    /// Octave words for alternative output
+    #[arg(long, value_enum, default_value_t = AlternativeStyle::Repeated)]
+    pub alternative_style: AlternativeStyle,
+
+    /// Reference frequency of A4 in Hz
+    #[arg(long, default_value_t = pitch::DEFAULT_A4_FREQUENCY, value_parser = parse_a4)]
+    pub a4: f64,
+
+    /// Formats to try first when detecting the input format, e.g. --prefer german,piano-key
+    #[arg(long, value_parser = notation_names(), value_delimiter = ',')]
+    pub prefer: Vec<String>,
+
+    /// Fail instead of guessing when the input reads as different pitches in different formats
+    #[arg(long)]
+    pub no_guess: bool,
+
+    /// Accept any letter case and spaces inside the pitch, and report how it was read
+    #[arg(long)]
+    pub lenient: bool,
+
+    /// Respell note names without an octave, e.g. C# as Db
+    #[arg(long, value_enum)]
+    pub spelling: Option<Spelling>,
+
+    /// Print accidentals as ♯, ♭, 𝄪 and 𝄫 instead of ASCII
+    #[arg(long)]
+    pub unicode: bool,
+}
+
+fn parse_a4(s: &str) -> Result<f64, String> {
+    let hz = s
+        .parse()
+        .map_err(|e: std::num::ParseFloatError| e.to_string())?;
+
+    Frequency::new(hz)
+        .map(|frequency| frequency.hz())
+        .map_err(|_| "must be a positive finite number of Hz".to_owned())
+}
+
+/// Names of the notations in the global registry, in detection order.
+fn notation_names() -> PossibleValuesParser {
+    let registry = NotationRegistry::global().read().unwrap();
+
+    PossibleValuesParser::new(
+        registry
+            .iter()
+            .map(|notation| notation.name())
+            .collect::<Vec<_>>(),
+    )
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum MiddleC {
+    C3,
+    C4,
+    C5,
+}
+
+impl From<MiddleC> for pitch::MiddleC {
+    fn from(value: MiddleC) -> Self {
+        match value {
+            MiddleC::C3 => pitch::MiddleC::C3,
+            MiddleC::C4 => pitch::MiddleC::C4,
+            MiddleC::C5 => pitch::MiddleC::C5,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OctaveBoundary {
+    C,
+    #[value(alias = "g#")]
+    GSharp,
+    A,
+}
+
+impl From<OctaveBoundary> for pitch::OctaveBoundary {
+    fn from(value: OctaveBoundary) -> Self {
+        match value {
+            OctaveBoundary::C => pitch::OctaveBoundary::C,
+            OctaveBoundary::GSharp => pitch::OctaveBoundary::GSharp,
+            OctaveBoundary::A => pitch::OctaveBoundary::A,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SolfegeVariant {
+    Italian,
+    French,
+}
+
+impl From<SolfegeVariant> for pitch::SolfegeVariant {
+    fn from(value: SolfegeVariant) -> Self {
+        match value {
+            SolfegeVariant::Italian => pitch::SolfegeVariant::Italian,
+            SolfegeVariant::French => pitch::SolfegeVariant::French,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum HelmholtzStyle {
+    Marks,
+    Indices,
+}
+
+impl From<HelmholtzStyle> for pitch::HelmholtzStyle {
+    fn from(value: HelmholtzStyle) -> Self {
+        match value {
+            HelmholtzStyle::Marks => pitch::HelmholtzStyle::Marks,
+            HelmholtzStyle::Indices => pitch::HelmholtzStyle::Indices,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum AlternativeStyle {
+    Repeated,
+    Numeric,
+}
+
+impl From<AlternativeStyle> for pitch::AlternativeStyle {
+    fn from(value: AlternativeStyle) -> Self {
+        match value {
+            AlternativeStyle::Repeated => pitch::AlternativeStyle::Repeated,
+            AlternativeStyle::Numeric => pitch::AlternativeStyle::Numeric,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Spelling {
+    Sharps,
+    Flats,
 }
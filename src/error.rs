@@ -1,4 +1,4 @@
-use std::num::ParseIntError;
+use std::num::{ParseFloatError, ParseIntError, TryFromIntError};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ParsePitchClassError;
@@ -9,6 +9,18 @@ impl From<ParseIntError> for ParsePitchError {
     }
 }
 
+impl From<ParseFloatError> for ParsePitchError {
+    fn from(_: ParseFloatError) -> Self {
+        ParsePitchError
+    }
+}
+
+impl From<TryFromIntError> for ParsePitchError {
+    fn from(_: TryFromIntError) -> Self {
+        ParsePitchError
+    }
+}
+
 impl From<ParsePitchClassError> for ParsePitchError {
     fn from(_: ParsePitchClassError) -> Self {
         ParsePitchError
@@ -17,3 +29,38 @@ impl From<ParsePitchClassError> for ParsePitchError {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ParsePitchError;
+
+impl From<PitchOutOfRangeError> for ParsePitchError {
+    fn from(_: PitchOutOfRangeError) -> Self {
+        ParsePitchError
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PitchOutOfRangeError;
+
+/// A frequency, such as the A4 reference, that is not a positive finite
+/// number of Hz.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidFrequencyError;
+
+impl From<InvalidFrequencyError> for ParsePitchError {
+    fn from(_: InvalidFrequencyError) -> Self {
+        ParsePitchError
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatPitchError;
+
+impl From<PitchOutOfRangeError> for FormatPitchError {
+    fn from(_: PitchOutOfRangeError) -> Self {
+        FormatPitchError
+    }
+}
+
+impl From<InvalidFrequencyError> for FormatPitchError {
+    fn from(_: InvalidFrequencyError) -> Self {
+        FormatPitchError
+    }
+}
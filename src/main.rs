@@ -1,34 +1,219 @@
 mod args;
-mod error;
-mod pitch;
 
 use std::io::{stdin, Read};
+use std::process;
 
 use clap::Parser;
-
-use crate::{
-    args::Args,
-    pitch::{PitchFormat, PitchWithFormat},
+use pitchconv::pitch::{
+    AccidentalStyle, AlternativeFormat, FormatOptions, NotationRegistry, ParseOptions, PitchClass,
+    PitchFormat, PitchWithFormat, Reading, ReadingWithFormat, ScientificFormat,
 };
 
+use crate::args::{Args, Spelling};
+
 fn main() {
-    let args = Args::parse();
+    let mut args = Args::parse();
 
-    let pitch = args.pitch.unwrap_or_else(|| {
+    let pitch = args.pitch.take().unwrap_or_else(|| {
         let mut buf = String::new();
         stdin().lock().read_to_string(&mut buf).unwrap();
         buf.truncate(buf.trim_end().len());
         buf
     });
 
-    let pitch_with_format: PitchWithFormat = pitch.parse().unwrap();
+    for name in &args.prefer {
+        NotationRegistry::global().write().unwrap().prefer(name);
+    }
+
+    let parse_options = ParseOptions {
+        middle_c: args.from_middle_c.into(),
+        a4_frequency: args.a4,
+        octave_boundary: args.from_octave_boundary.into(),
+        lenient: args.lenient,
+    };
+
+    let accidental_style = if args.unicode {
+        AccidentalStyle::Unicode
+    } else {
+        AccidentalStyle::Ascii
+    };
+
+    let format_options = FormatOptions {
+        middle_c: args.to_middle_c.into(),
+        a4_frequency: args.a4,
+        octave_boundary: args.to_octave_boundary.into(),
+        accidental_style,
+        solfege_variant: args.solfege_variant.into(),
+        helmholtz_style: args.helmholtz_style.into(),
+        alternative_style: args.alternative_style.into(),
+    };
 
-    match pitch_with_format.format {
-        PitchFormat::ScientificPitchNotation => {
-            println!("{}", pitch_with_format.pitch.alternative_pitch_notation());
+    // A note name without an octave, e.g. C# or Cis, is converted as a pitch
+    // class.
+    let ReadingWithFormat { reading, format } = match &args.from {
+        Some(from) => read_as(from, &pitch, &parse_options),
+        None => detect_format(&pitch, &parse_options, &args),
+    };
+
+    match reading {
+        Reading::Pitch(p) => convert_pitch(
+            &pitch,
+            PitchWithFormat { pitch: p, format },
+            &format_options,
+            &args,
+        ),
+        Reading::PitchClass(pitch_class) => {
+            convert_pitch_class(&pitch, pitch_class, format, &format_options, &args)
         }
-        PitchFormat::AlternativePitchNotation => {
-            println!("{}", pitch_with_format.pitch.scientific_pitch_notation());
+    }
+}
+
+/// Reads `input` in the notation called `from`, as a pitch or else as a
+/// note name without an octave.
+fn read_as(from: &str, input: &str, options: &ParseOptions) -> ReadingWithFormat {
+    let format = PitchFormat::named(from).unwrap();
+
+    let reading = match format.parse(input, options) {
+        Ok(pitch) => Reading::Pitch(pitch),
+        Err(_) => match format.parse_pitch_class(input, options) {
+            Ok(pitch_class) => Reading::PitchClass(pitch_class),
+            Err(_) => not_a_pitch(input, &format!("the {from} format")),
+        },
+    };
+
+    ReadingWithFormat { reading, format }
+}
+
+/// Detects the input format. See [`report_ambiguity`].
+fn detect_format(input: &str, options: &ParseOptions, args: &Args) -> ReadingWithFormat {
+    let candidates = NotationRegistry::global()
+        .read()
+        .unwrap()
+        .read_all(input, options);
+    let readings: Vec<_> = candidates
+        .iter()
+        .map(|c| (c.format.name(), c.reading.to_string()))
+        .collect();
+
+    report_ambiguity(input, &readings, args);
+
+    candidates
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| not_a_pitch(input, "any known format"))
+}
+
+/// Reports that `input` does not read as a pitch in `formats` and exits.
+fn not_a_pitch(input: &str, formats: &str) -> ! {
+    eprintln!("error: \"{input}\" is not a pitch in {formats}");
+    process::exit(1);
+}
+
+/// Warns when `readings` of the input, as pairs of notation name and what it
+/// reads, disagree, unless the first one was asked for with `--prefer`. With
+/// `--no-guess` that is an error instead.
+fn report_ambiguity(input: &str, readings: &[(&str, String)], args: &Args) {
+    let Some((first_name, first_reading)) = readings.first() else {
+        return;
+    };
+
+    if readings.iter().all(|(_, reading)| reading == first_reading)
+        || args.prefer.iter().any(|name| name == first_name)
+    {
+        return;
+    }
+
+    let readings = readings
+        .iter()
+        .map(|(name, reading)| format!("{name} ({reading})"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    if args.no_guess {
+        eprintln!("error: \"{input}\" is ambiguous: {readings}; pass --from to choose");
+        process::exit(1);
+    }
+
+    eprintln!(
+        "warning: \"{input}\" is ambiguous: {readings}; reading it as {first_name}, pass --from or --prefer to choose",
+    );
+}
+
+fn convert_pitch(
+    input: &str,
+    pitch_with_format: PitchWithFormat,
+    format_options: &FormatOptions,
+    args: &Args,
+) {
+    let output_format = match &args.to {
+        Some(to) => PitchFormat::named(to).unwrap(),
+        None if pitch_with_format.format == PitchFormat::of(&ScientificFormat) => {
+            PitchFormat::of(&AlternativeFormat)
+        }
+        None => PitchFormat::of(&ScientificFormat),
+    };
+
+    if args.lenient {
+        let canonical = pitch_with_format.format.format(
+            &pitch_with_format.pitch,
+            &FormatOptions {
+                middle_c: args.from_middle_c.into(),
+                octave_boundary: args.from_octave_boundary.into(),
+                ..*format_options
+            },
+        );
+
+        if let Some(canonical) = canonical.ok().filter(|canonical| canonical != input) {
+            eprintln!("Interpreted \"{input}\" as {canonical}");
+        }
+    }
+
+    match output_format.format(&pitch_with_format.pitch, format_options) {
+        Ok(output) => println!("{output}"),
+        Err(_) => {
+            eprintln!(
+                "error: {} is out of range for {}",
+                pitch_with_format.pitch,
+                output_format.name(),
+            );
+            process::exit(1);
+        }
+    }
+}
+
+fn convert_pitch_class(
+    input: &str,
+    pitch_class: PitchClass,
+    format: PitchFormat,
+    format_options: &FormatOptions,
+    args: &Args,
+) {
+    if args.lenient {
+        let canonical = format.format_pitch_class(&pitch_class, format_options);
+
+        if let Some(canonical) = canonical.ok().filter(|canonical| canonical != input) {
+            eprintln!("Interpreted \"{input}\" as {canonical}");
+        }
+    }
+
+    let pitch_class = match args.spelling {
+        Some(Spelling::Sharps) => pitch_class.sharp_spelling(),
+        Some(Spelling::Flats) => pitch_class.flat_spelling(),
+        None => pitch_class,
+    };
+    let output_format = match &args.to {
+        Some(to) => PitchFormat::named(to).unwrap(),
+        None => PitchFormat::of(&ScientificFormat),
+    };
+
+    match output_format.format_pitch_class(&pitch_class, format_options) {
+        Ok(output) => println!("{output}"),
+        Err(_) => {
+            eprintln!(
+                "error: --to {} needs an octave, but \"{input}\" has none",
+                output_format.name(),
+            );
+            process::exit(1);
         }
     }
 }
@@ -1,48 +1,230 @@
 use regex::Regex;
 
-use crate::error::{ParsePitchClassError, ParsePitchError};
+use crate::error::{
+    FormatPitchError, InvalidFrequencyError, ParsePitchClassError, ParsePitchError,
+    PitchOutOfRangeError,
+};
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub enum PitchClass {
+mod abc;
+mod csound;
+mod german;
+mod helmholtz;
+mod japanese;
+mod lenient;
+mod lilypond;
+mod mml;
+mod normalize;
+mod notation;
+mod pitch_class;
+mod solfege;
+mod tracker;
+
+pub use abc::{AbcFormat, AbcNotation};
+pub use csound::{CsoundOctFormat, CsoundOctNotation, CsoundPchFormat, CsoundPchNotation};
+pub use german::{GermanFormat, GermanPitchNotation};
+pub use helmholtz::{HelmholtzFormat, HelmholtzPitchNotation, HelmholtzStyle};
+pub use japanese::{
+    JapaneseFormat, JapanesePitchNotation, KatakanaSolfegeFormat, KatakanaSolfegeNotation,
+};
+pub use lilypond::{LilyPondFormat, LilyPondNotation};
+pub use mml::{parse_mml, MmlNotation};
+pub use normalize::normalize;
+pub use notation::{FormatOptions, NotationRegistry, PitchNotation, Reading, ReadingWithFormat};
+pub use pitch_class::{PitchClassFormat, PitchClassNotation, PitchClassWithFormat};
+pub use solfege::{SolfegeFormat, SolfegeNotation, SolfegeVariant};
+pub use tracker::{parse_tracker_note, TrackerFormat, TrackerNotation, TRACKER_EMPTY_NOTE};
+
+use lenient::Recase;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Letter {
     C,
-    CSharp,
     D,
-    DSharp,
     E,
     F,
-    FSharp,
     G,
-    GSharp,
     A,
-    ASharp,
     B,
 }
 
-impl PitchClass {
-    pub fn as_str(&self) -> &str {
+impl Letter {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Letter::C => "C",
+            Letter::D => "D",
+            Letter::E => "E",
+            Letter::F => "F",
+            Letter::G => "G",
+            Letter::A => "A",
+            Letter::B => "B",
+        }
+    }
+
+    pub fn semitone(&self) -> i8 {
         match self {
-            PitchClass::C => "C",
-            PitchClass::CSharp => "C#",
-            PitchClass::D => "D",
-            PitchClass::DSharp => "D#",
-            PitchClass::E => "E",
-            PitchClass::F => "F",
-            PitchClass::FSharp => "F#",
-            PitchClass::G => "G",
-            PitchClass::GSharp => "G#",
-            PitchClass::A => "A",
-            PitchClass::ASharp => "A#",
-            PitchClass::B => "B",
+            Letter::C => 0,
+            Letter::D => 2,
+            Letter::E => 4,
+            Letter::F => 5,
+            Letter::G => 7,
+            Letter::A => 9,
+            Letter::B => 11,
         }
     }
 }
 
-impl std::fmt::Display for PitchClass {
+impl std::fmt::Display for Letter {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Accidental {
+    DoubleFlat,
+    Flat,
+    Natural,
+    Sharp,
+    DoubleSharp,
+}
+
+impl Accidental {
+    pub fn as_str(&self) -> &'static str {
+        self.symbol(AccidentalStyle::Ascii)
+    }
+
+    pub fn symbol(&self, style: AccidentalStyle) -> &'static str {
+        match (self, style) {
+            (Accidental::DoubleFlat, AccidentalStyle::Ascii) => "bb",
+            (Accidental::Flat, AccidentalStyle::Ascii) => "b",
+            (Accidental::Natural, _) => "",
+            (Accidental::Sharp, AccidentalStyle::Ascii) => "#",
+            (Accidental::DoubleSharp, AccidentalStyle::Ascii) => "##",
+            (Accidental::DoubleFlat, AccidentalStyle::Unicode) => "𝄫",
+            (Accidental::Flat, AccidentalStyle::Unicode) => "♭",
+            (Accidental::Sharp, AccidentalStyle::Unicode) => "♯",
+            (Accidental::DoubleSharp, AccidentalStyle::Unicode) => "𝄪",
+        }
+    }
+
+    pub fn offset(&self) -> i8 {
+        match self {
+            Accidental::DoubleFlat => -2,
+            Accidental::Flat => -1,
+            Accidental::Natural => 0,
+            Accidental::Sharp => 1,
+            Accidental::DoubleSharp => 2,
+        }
+    }
+}
+
+impl std::fmt::Display for Accidental {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "{}", self.as_str())
     }
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AccidentalStyle {
+    #[default]
+    Ascii,
+    Unicode,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PitchClass {
+    pub letter: Letter,
+    pub accidental: Accidental,
+}
+
+impl PitchClass {
+    pub const fn new(letter: Letter, accidental: Accidental) -> Self {
+        PitchClass { letter, accidental }
+    }
+
+    /// Semitones above the natural C of the same octave number.
+    ///
+    /// Spellings such as `Cb` or `B#` fall outside `0..12`, since their
+    /// letter decides the octave they are written in.
+    pub fn semitone(&self) -> i8 {
+        self.letter.semitone() + self.accidental.offset()
+    }
+
+    /// Spells a semitone (taken modulo 12) with sharps, e.g. 1 becomes `C#`.
+    pub fn from_semitone(semitone: i8) -> Self {
+        let (letter, accidental) = match semitone.rem_euclid(12) {
+            0 => (Letter::C, Accidental::Natural),
+            1 => (Letter::C, Accidental::Sharp),
+            2 => (Letter::D, Accidental::Natural),
+            3 => (Letter::D, Accidental::Sharp),
+            4 => (Letter::E, Accidental::Natural),
+            5 => (Letter::F, Accidental::Natural),
+            6 => (Letter::F, Accidental::Sharp),
+            7 => (Letter::G, Accidental::Natural),
+            8 => (Letter::G, Accidental::Sharp),
+            9 => (Letter::A, Accidental::Natural),
+            10 => (Letter::A, Accidental::Sharp),
+            _ => (Letter::B, Accidental::Natural),
+        };
+
+        PitchClass::new(letter, accidental)
+    }
+
+    pub fn is_enharmonic_to(&self, other: &PitchClass) -> bool {
+        self.semitone().rem_euclid(12) == other.semitone().rem_euclid(12)
+    }
+
+    /// Enharmonic spelling with naturals and sharps only, e.g. `Db` becomes
+    /// `C#` and `E#` becomes `F`.
+    pub fn sharp_spelling(&self) -> Self {
+        PitchClass::from_semitone(self.semitone())
+    }
+
+    /// Enharmonic spelling with naturals and flats only, e.g. `C#` becomes
+    /// `Db` and `Fb` becomes `E`.
+    pub fn flat_spelling(&self) -> Self {
+        match PitchClass::from_semitone(self.semitone()) {
+            PitchClass {
+                letter,
+                accidental: Accidental::Sharp,
+            } => PitchClass::new(
+                match letter {
+                    Letter::C => Letter::D,
+                    Letter::D => Letter::E,
+                    Letter::F => Letter::G,
+                    Letter::G => Letter::A,
+                    _ => Letter::B,
+                },
+                Accidental::Flat,
+            ),
+            pitch_class => pitch_class,
+        }
+    }
+}
+
+/// Orders by [`PitchClass::semitone`], then by spelling.
+impl Ord for PitchClass {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.semitone(), self.letter, self.accidental).cmp(&(
+            other.semitone(),
+            other.letter,
+            other.accidental,
+        ))
+    }
+}
+
+impl PartialOrd for PitchClass {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl std::fmt::Display for PitchClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}{}", self.letter, self.accidental)
+    }
+}
+
 impl std::str::FromStr for PitchClass {
     type Err = ParsePitchClassError;
 
@@ -52,38 +234,198 @@ impl std::str::FromStr for PitchClass {
 }
 
 fn parse_pitch_class(s: &str) -> Result<PitchClass, ParsePitchClassError> {
-    let pitch_class = match s {
-        "C" => PitchClass::C,
-        "C#" => PitchClass::CSharp,
-        "D" => PitchClass::D,
-        "D#" => PitchClass::DSharp,
-        "E" => PitchClass::E,
-        "F" => PitchClass::F,
-        "F#" => PitchClass::FSharp,
-        "G" => PitchClass::G,
-        "G#" => PitchClass::GSharp,
-        "A" => PitchClass::A,
-        "A#" => PitchClass::ASharp,
-        "B" => PitchClass::B,
+    let mut chars = s.chars();
+
+    let letter = match chars.next() {
+        Some('C') => Letter::C,
+        Some('D') => Letter::D,
+        Some('E') => Letter::E,
+        Some('F') => Letter::F,
+        Some('G') => Letter::G,
+        Some('A') => Letter::A,
+        Some('B') => Letter::B,
         _ => return Err(ParsePitchClassError),
     };
 
-    Ok(pitch_class)
+    let accidental = match chars.as_str() {
+        "" | "♮" => Accidental::Natural,
+        "#" | "♯" => Accidental::Sharp,
+        "##" | "x" | "♯♯" | "𝄪" => Accidental::DoubleSharp,
+        "b" | "♭" => Accidental::Flat,
+        "bb" | "♭♭" | "𝄫" => Accidental::DoubleFlat,
+        _ => return Err(ParsePitchClassError),
+    };
+
+    Ok(PitchClass::new(letter, accidental))
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Pitch {
-    pub octave: u8,
+    pub octave: i16,
     pub pitch_class: PitchClass,
+    /// Deviation from the spelled pitch, in cents. Kept within
+    /// `-MAX_CENTS..=MAX_CENTS` by [`Pitch::with_cents`].
+    cents: i16,
 }
 
 impl Pitch {
-    pub fn scientific_pitch_notation(&self) -> ScientificPitchNotation {
-        ScientificPitchNotation(self)
+    pub const MIN_OCTAVE: i16 = -255;
+    pub const MAX_OCTAVE: i16 = 255;
+
+    /// Largest deviation the notations can write, e.g. `A4+99c`. Larger ones
+    /// are a different spelled pitch.
+    pub const MAX_CENTS: i16 = 99;
+
+    pub const MAX_MIDI_NOTE_NUMBER: u8 = 127;
+
+    pub const PIANO_KEYS: u8 = 88;
+
+    pub const fn new(pitch_class: PitchClass, octave: i16) -> Self {
+        Pitch {
+            octave,
+            pitch_class,
+            cents: 0,
+        }
+    }
+
+    pub fn cents(&self) -> i16 {
+        self.cents
+    }
+
+    /// This pitch `cents` above or below the spelled pitch. Fails unless
+    /// `cents` is within `-MAX_CENTS..=MAX_CENTS`.
+    pub fn with_cents(self, cents: i16) -> Result<Self, PitchOutOfRangeError> {
+        if !(-Pitch::MAX_CENTS..=Pitch::MAX_CENTS).contains(&cents) {
+            return Err(PitchOutOfRangeError);
+        }
+
+        Ok(Pitch { cents, ..self })
+    }
+
+    /// `octave` as a [`Pitch::octave`], if it is within
+    /// `MIN_OCTAVE..=MAX_OCTAVE`.
+    pub fn checked_octave(octave: i32) -> Result<i16, ParsePitchError> {
+        if !(Pitch::MIN_OCTAVE as i32..=Pitch::MAX_OCTAVE as i32).contains(&octave) {
+            return Err(ParsePitchError);
+        }
+
+        Ok(octave as _)
+    }
+
+    /// Inverse of [`Pitch::semitones`], spelled with sharps.
+    pub fn from_semitones(semitones: i32) -> Result<Self, PitchOutOfRangeError> {
+        let octave =
+            Pitch::checked_octave(semitones.div_euclid(12)).map_err(|_| PitchOutOfRangeError)?;
+
+        Ok(Pitch {
+            octave,
+            pitch_class: PitchClass::from_semitone(semitones.rem_euclid(12) as _),
+            cents: 0,
+        })
+    }
+
+    /// Semitones above C0, taking the accidental but not the cents into account.
+    pub fn semitones(&self) -> i32 {
+        self.octave as i32 * 12 + self.pitch_class.semitone() as i32
+    }
+
+    /// MIDI note number 0 is C-1 and 60 is middle C (C4).
+    pub fn from_midi(note_number: u8) -> Result<Self, PitchOutOfRangeError> {
+        if note_number > Self::MAX_MIDI_NOTE_NUMBER {
+            return Err(PitchOutOfRangeError);
+        }
+
+        Pitch::from_semitones(note_number as i32 - 12)
+    }
+
+    pub fn to_midi(&self) -> Result<u8, PitchOutOfRangeError> {
+        match self.semitones() + 12 {
+            n @ 0..=127 => Ok(n as _),
+            _ => Err(PitchOutOfRangeError),
+        }
+    }
+
+    /// Key 1 is the lowest key of an 88-key piano (A0) and 88 the highest
+    /// (C8).
+    pub fn from_piano_key(key_number: u8) -> Result<Self, PitchOutOfRangeError> {
+        if !(1..=Self::PIANO_KEYS).contains(&key_number) {
+            return Err(PitchOutOfRangeError);
+        }
+
+        Pitch::from_semitones(key_number as i32 + 8)
+    }
+
+    pub fn to_piano_key(&self) -> Result<u8, PitchOutOfRangeError> {
+        match self.semitones() - 8 {
+            n @ 1..=88 => Ok(n as _),
+            _ => Err(PitchOutOfRangeError),
+        }
+    }
+
+    /// Nearest pitch to `frequency`, with the deviation from it in cents.
+    /// Fails when `a4_frequency` is not a positive finite number of Hz or
+    /// the pitch is out of range.
+    pub fn from_frequency(
+        frequency: Frequency,
+        a4_frequency: f64,
+    ) -> Result<Self, ParsePitchError> {
+        let a4_frequency = Frequency::new(a4_frequency)?;
+        let semitones = 12.0 * (frequency.hz() / a4_frequency.hz()).log2() + A4_SEMITONES as f64;
+        let nearest = semitones.round();
+
+        Ok(Pitch {
+            cents: ((semitones - nearest) * 100.0).round() as _,
+            ..Pitch::from_semitones(nearest as _)?
+        })
+    }
+
+    /// Fails when `a4_frequency`, or the frequency of this pitch relative to
+    /// it, is not a positive finite number of Hz.
+    pub fn frequency(&self, a4_frequency: f64) -> Result<Frequency, InvalidFrequencyError> {
+        let a4_frequency = Frequency::new(a4_frequency)?;
+        let semitones = (self.semitones() - A4_SEMITONES) as f64 + self.cents as f64 / 100.0;
+
+        Frequency::new(a4_frequency.hz() * (semitones / 12.0).exp2())
+    }
+
+    pub fn is_enharmonic_to(&self, other: &Pitch) -> bool {
+        self.semitones() == other.semitones() && self.cents == other.cents
+    }
+
+    pub fn scientific_pitch_notation(&self) -> ScientificPitchNotation<'_> {
+        ScientificPitchNotation {
+            pitch: self,
+            accidental_style: AccidentalStyle::default(),
+            middle_c: MiddleC::default(),
+        }
+    }
+
+    pub fn alternative_pitch_notation(&self) -> AlternativePitchNotation<'_> {
+        AlternativePitchNotation {
+            pitch: self,
+            accidental_style: AccidentalStyle::default(),
+            style: AlternativeStyle::default(),
+            octave_boundary: OctaveBoundary::default(),
+        }
     }
+}
+
+/// Orders by sounding pitch, then by spelling, so `B#3` and `C4` sort next
+/// to each other.
+impl Ord for Pitch {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.semitones(), self.cents, self.octave, self.pitch_class).cmp(&(
+            other.semitones(),
+            other.cents,
+            other.octave,
+            other.pitch_class,
+        ))
+    }
+}
 
-    pub fn alternative_pitch_notation(&self) -> AlternativePitchNotation {
-        AlternativePitchNotation(self)
+impl PartialOrd for Pitch {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
     }
 }
 
@@ -107,10 +449,214 @@ impl std::str::FromStr for Pitch {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum PitchFormat {
-    ScientificPitchNotation,
-    AlternativePitchNotation,
+const A4_SEMITONES: i32 = 4 * 12 + 9;
+
+pub const DEFAULT_A4_FREQUENCY: f64 = 440.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Frequency(f64);
+
+impl Frequency {
+    /// Fails unless `hz` is a positive finite number.
+    pub fn new(hz: f64) -> Result<Self, InvalidFrequencyError> {
+        if !hz.is_finite() || hz <= 0.0 {
+            return Err(InvalidFrequencyError);
+        }
+
+        Ok(Frequency(hz))
+    }
+
+    pub fn hz(&self) -> f64 {
+        self.0
+    }
+}
+
+impl std::fmt::Display for Frequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:.2}Hz", self.0)
+    }
+}
+
+impl std::str::FromStr for Frequency {
+    type Err = ParsePitchError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_frequency(s)
+    }
+}
+
+fn parse_frequency(s: &str) -> Result<Frequency, ParsePitchError> {
+    let Some(caps) = Regex::new(r"^(?<hz>\d+(\.\d+)?) ?Hz$").unwrap().captures(s) else {
+        return Err(ParsePitchError);
+    };
+
+    let hz: f64 = caps.name("hz").unwrap().as_str().parse()?;
+
+    Ok(Frequency::new(hz)?)
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FrequencyFormat;
+
+impl PitchNotation for FrequencyFormat {
+    fn name(&self) -> &'static str {
+        "frequency"
+    }
+
+    fn priority(&self) -> i32 {
+        140
+    }
+
+    fn parse(&self, s: &str, options: &ParseOptions) -> Result<Pitch, ParsePitchError> {
+        let frequency = parse_frequency(&lenient::prepare(s, options, Recase::Frequency))?;
+
+        Pitch::from_frequency(frequency, options.a4_frequency)
+    }
+
+    fn format(&self, pitch: &Pitch, options: &FormatOptions) -> Result<String, FormatPitchError> {
+        Ok(pitch.frequency(options.a4_frequency)?.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MiddleC {
+    C3,
+    #[default]
+    C4,
+    /// Impulse Tracker and OpenMPT, where middle C is `C-5`.
+    C5,
+}
+
+impl MiddleC {
+    /// Octaves to add to a written octave number to get the C4-based one.
+    fn octave_offset(&self) -> i32 {
+        match self {
+            MiddleC::C3 => 1,
+            MiddleC::C4 => 0,
+            MiddleC::C5 => -1,
+        }
+    }
+}
+
+/// First pitch class of each octave word in alternative notation. With
+/// the common [`OctaveBoundary::A`], `hiA` is a step above `mid2G#`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OctaveBoundary {
+    C,
+    GSharp,
+    #[default]
+    A,
+}
+
+impl OctaveBoundary {
+    /// Whether `pitch_class` belongs to the octave word above its
+    /// scientific octave.
+    fn is_above(&self, pitch_class: &PitchClass) -> bool {
+        match (self, pitch_class.letter) {
+            (OctaveBoundary::C, _) => false,
+            (_, Letter::A | Letter::B) => true,
+            (OctaveBoundary::GSharp, Letter::G) => pitch_class.accidental.offset() > 0,
+            (_, _) => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseOptions {
+    pub middle_c: MiddleC,
+    pub a4_frequency: f64,
+    pub octave_boundary: OctaveBoundary,
+    /// Accept any letter case and spaces inside the pitch, e.g. `c4`,
+    /// `HI A#` or `mid 2 C`, where the format allows it.
+    pub lenient: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            middle_c: MiddleC::default(),
+            a4_frequency: DEFAULT_A4_FREQUENCY,
+            octave_boundary: OctaveBoundary::default(),
+            lenient: false,
+        }
+    }
+}
+
+/// Identifies a notation in the [`NotationRegistry`] by its
+/// [`PitchNotation::name`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PitchFormat(&'static str);
+
+impl PitchFormat {
+    pub fn of(notation: &dyn PitchNotation) -> Self {
+        PitchFormat(notation.name())
+    }
+
+    /// The format of the notation called `name` in
+    /// [`NotationRegistry::global`], if there is one.
+    pub fn named(name: &str) -> Option<Self> {
+        let registry = NotationRegistry::global().read().unwrap();
+
+        registry.get(name).map(PitchFormat::of)
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.0
+    }
+
+    /// Parses `s` with the notation of this name in
+    /// [`NotationRegistry::global`].
+    pub fn parse(&self, s: &str, options: &ParseOptions) -> Result<Pitch, ParsePitchError> {
+        let registry = NotationRegistry::global().read().unwrap();
+        let notation = registry.get(self.name()).ok_or(ParsePitchError)?;
+
+        notation::parse_with(s, options, |s, options| notation.parse(s, options))
+    }
+
+    /// Parses a note name without an octave, e.g. `C#`, with the notation of
+    /// this name in [`NotationRegistry::global`].
+    pub fn parse_pitch_class(
+        &self,
+        s: &str,
+        options: &ParseOptions,
+    ) -> Result<PitchClass, ParsePitchClassError> {
+        let registry = NotationRegistry::global().read().unwrap();
+        let notation = registry.get(self.name()).ok_or(ParsePitchClassError)?;
+
+        notation::parse_with(s, options, |s, options| {
+            notation.parse_pitch_class(s, options)
+        })
+    }
+
+    /// Writes `pitch` with the notation of this name in
+    /// [`NotationRegistry::global`].
+    pub fn format(
+        &self,
+        pitch: &Pitch,
+        options: &FormatOptions,
+    ) -> Result<String, FormatPitchError> {
+        let registry = NotationRegistry::global().read().unwrap();
+
+        registry
+            .get(self.name())
+            .ok_or(FormatPitchError)?
+            .format(pitch, options)
+    }
+
+    /// Writes `pitch_class` with the notation of this name in
+    /// [`NotationRegistry::global`].
+    pub fn format_pitch_class(
+        &self,
+        pitch_class: &PitchClass,
+        options: &FormatOptions,
+    ) -> Result<String, FormatPitchError> {
+        let registry = NotationRegistry::global().read().unwrap();
+
+        registry
+            .get(self.name())
+            .ok_or(FormatPitchError)?
+            .format_pitch_class(pitch_class, options)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -119,133 +665,378 @@ pub struct PitchWithFormat {
     pub format: PitchFormat,
 }
 
+impl PitchWithFormat {
+    /// Reads `s` in the first notation of [`NotationRegistry::global`] that
+    /// accepts it.
+    pub fn parse(s: &str, options: &ParseOptions) -> Result<Self, ParsePitchError> {
+        NotationRegistry::global().read().unwrap().parse(s, options)
+    }
+
+    /// Every reading of `s`, in the detection order of
+    /// [`NotationRegistry::global`]. More than one reading with different
+    /// pitches means the input is ambiguous, e.g. `60` as a MIDI note number
+    /// or a piano key.
+    pub fn parse_all(s: &str, options: &ParseOptions) -> Vec<Self> {
+        NotationRegistry::global()
+            .read()
+            .unwrap()
+            .parse_all(s, options)
+    }
+}
+
 impl std::str::FromStr for PitchWithFormat {
     type Err = ParsePitchError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Ok(pitch) = parse_scientific_pitch_notation(s) {
-            return Ok(PitchWithFormat {
-                pitch,
-                format: PitchFormat::ScientificPitchNotation,
-            });
-        }
+        PitchWithFormat::parse(s, &ParseOptions::default())
+    }
+}
 
-        if let Ok(pitch) = parse_alternative_pitch_notation(s) {
-            return Ok(PitchWithFormat {
-                pitch,
-                format: PitchFormat::AlternativePitchNotation,
-            });
-        }
+/// Octave written as a bare lowercase letter: the "small" octave of
+/// Helmholtz, LilyPond and Japanese (小字) names.
+pub(super) const SMALL_OCTAVE: i32 = 3;
+
+const ACCIDENTAL_PATTERN: &str = r"(##|#|x|bb|b|♯♯|♯|𝄪|♭♭|♭|𝄫|♮)";
+
+const CENTS_PATTERN: &str = r"[+-](0|[1-9]\d?)c";
+
+fn parse_cents(s: Option<&str>) -> Result<i16, ParsePitchError> {
+    match s {
+        Some(s) => Ok(s.trim_end_matches('c').parse()?),
+        None => Ok(0),
+    }
+}
 
-        Err(ParsePitchError)
+fn fmt_cents(cents: i16, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    match cents {
+        0 => Ok(()),
+        cents => write!(f, "{cents:+}c"),
     }
 }
 
-fn parse_scientific_pitch_notation(s: &str) -> Result<Pitch, ParsePitchError> {
-    let Some(caps) = Regex::new(r"^(?<pitch_class>[A-G][#]?)(?<octave>0|([1-9]\d*))$")
-        .unwrap()
-        .captures(s)
-    else {
+fn parse_scientific_pitch_notation(
+    s: &str,
+    options: &ParseOptions,
+) -> Result<Pitch, ParsePitchError> {
+    let Some(caps) = Regex::new(&format!(
+        r"^(?<pitch_class>[A-G]{ACCIDENTAL_PATTERN}?)(?<octave>0|(-?[1-9]\d*))(?<cents>{CENTS_PATTERN})?$"
+    ))
+    .unwrap()
+    .captures(s) else {
         return Err(ParsePitchError);
     };
 
-    let octave = caps.name("octave").unwrap().as_str().parse()?;
+    let octave =
+        caps.name("octave").unwrap().as_str().parse::<i32>()? + options.middle_c.octave_offset();
     let pitch_class = caps.name("pitch_class").unwrap().as_str().parse()?;
+    let cents = parse_cents(caps.name("cents").map(|m| m.as_str()))?;
+
+    let octave = Pitch::checked_octave(octave)?;
 
     Ok(Pitch {
         octave,
         pitch_class,
+        cents,
     })
 }
 
-fn parse_alternative_pitch_notation(s: &str) -> Result<Pitch, ParsePitchError> {
-    let Some(caps) =
-        Regex::new(r"^(?<octave>low|lowlow|lowlowlow|mid[12]|(hi)+)(?<pitch_class>[A-G][#]?)$")
-            .unwrap()
-            .captures(s)
-    else {
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScientificFormat;
+
+impl PitchNotation for ScientificFormat {
+    fn name(&self) -> &'static str {
+        "scientific"
+    }
+
+    fn priority(&self) -> i32 {
+        0
+    }
+
+    fn parse(&self, s: &str, options: &ParseOptions) -> Result<Pitch, ParsePitchError> {
+        parse_scientific_pitch_notation(&lenient::prepare(s, options, Recase::Capitalize), options)
+    }
+
+    fn format(&self, pitch: &Pitch, options: &FormatOptions) -> Result<String, FormatPitchError> {
+        Ok(pitch
+            .scientific_pitch_notation()
+            .accidental_style(options.accidental_style)
+            .middle_c(options.middle_c)
+            .to_string())
+    }
+
+    fn parse_pitch_class(
+        &self,
+        s: &str,
+        options: &ParseOptions,
+    ) -> Result<PitchClass, ParsePitchClassError> {
+        PitchClassFormat::Letter.parse_normalized(s, options)
+    }
+
+    fn format_pitch_class(
+        &self,
+        pitch_class: &PitchClass,
+        options: &FormatOptions,
+    ) -> Result<String, FormatPitchError> {
+        Ok(PitchClassFormat::Letter.format(pitch_class, options))
+    }
+}
+
+fn parse_alternative_pitch_notation(
+    s: &str,
+    options: &ParseOptions,
+) -> Result<Pitch, ParsePitchError> {
+    let Some(caps) = Regex::new(&format!(
+        r"^(?<octave>(low)+|low(?<low_count>[1-9]\d*)|mid[12]|m[12]|(hi)+|hi(?<hi_count>[1-9]\d*)|(?<hi_count_prefix>[1-9]\d*)hi)(?<pitch_class>[A-G]{ACCIDENTAL_PATTERN}?)(?<cents>{CENTS_PATTERN})?$"
+    ))
+    .unwrap()
+    .captures(s) else {
         return Err(ParsePitchError);
     };
 
-    let pitch_class = caps.name("pitch_class").unwrap().as_str().parse()?;
+    let pitch_class: PitchClass = caps.name("pitch_class").unwrap().as_str().parse()?;
+    let cents = parse_cents(caps.name("cents").map(|m| m.as_str()))?;
 
     let octave = {
         let octave_str = caps.name("octave").unwrap().as_str();
 
-        let base_octave = match octave_str {
-            "lowlowlow" => 0,
-            "lowlow" => 1,
-            "low" => 2,
-            "mid1" => 3,
-            "mid2" => 4,
-            s => {
-                let count = s.matches("hi").count();
-
-                if count == 0 {
-                    return Err(ParsePitchError);
-                }
+        let base_octave: i64 = if let Some(count) = caps.name("low_count") {
+            3 - count.as_str().parse::<i64>()?
+        } else if let Some(count) = caps.name("hi_count").or(caps.name("hi_count_prefix")) {
+            count.as_str().parse::<i64>()? + 4
+        } else {
+            match octave_str {
+                "mid1" | "m1" => 3,
+                "mid2" | "m2" => 4,
+                s if s.starts_with("low") => 3 - s.matches("low").count() as i64,
+                s => {
+                    let count = s.matches("hi").count();
 
-                count + 4
+                    if count == 0 {
+                        return Err(ParsePitchError);
+                    }
+
+                    count as i64 + 4
+                }
             }
         };
 
-        match pitch_class {
-            PitchClass::A | PitchClass::ASharp | PitchClass::B => base_octave - 1,
-            _ => base_octave,
+        if options.octave_boundary.is_above(&pitch_class) {
+            base_octave - 1
+        } else {
+            base_octave
         }
     };
 
-    if octave > u8::MAX as _ {
-        return Err(ParsePitchError);
-    }
+    let octave = Pitch::checked_octave(octave.try_into()?)?;
 
     Ok(Pitch {
-        octave: octave as _,
+        octave,
         pitch_class,
+        cents,
     })
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct ScientificPitchNotation<'a>(&'a Pitch);
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AlternativeFormat;
 
-impl std::fmt::Display for ScientificPitchNotation<'_> {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{}{}", self.0.pitch_class, self.0.octave)
+impl PitchNotation for AlternativeFormat {
+    fn name(&self) -> &'static str {
+        "alternative"
     }
-}
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct AlternativePitchNotation<'a>(&'a Pitch);
+    fn priority(&self) -> i32 {
+        10
+    }
 
-impl std::fmt::Display for AlternativePitchNotation<'_> {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        let o = match self.0.pitch_class {
-            PitchClass::A | PitchClass::ASharp | PitchClass::B => self.0.octave as u16 + 1,
-            _ => self.0.octave as u16,
-        };
+    fn parse(&self, s: &str, options: &ParseOptions) -> Result<Pitch, ParsePitchError> {
+        parse_alternative_pitch_notation(
+            &lenient::prepare(s, options, Recase::AlternativeNotation),
+            options,
+        )
+    }
 
-        match o {
-            0 => write!(f, "lowlowlow")?,
-            1 => write!(f, "lowlow")?,
-            2 => write!(f, "low")?,
-            3 => write!(f, "mid1")?,
-            4 => write!(f, "mid2")?,
-            n => {
-                for _ in 0..n - 4 {
-                    write!(f, "hi")?;
-                }
-            }
-        }
+    fn format(&self, pitch: &Pitch, options: &FormatOptions) -> Result<String, FormatPitchError> {
+        Ok(pitch
+            .alternative_pitch_notation()
+            .accidental_style(options.accidental_style)
+            .style(options.alternative_style)
+            .octave_boundary(options.octave_boundary)
+            .to_string())
+    }
+}
 
-        write!(f, "{}", self.0.pitch_class)
+fn parse_midi_note_number(s: &str) -> Result<Pitch, ParsePitchError> {
+    if !Regex::new(r"^(0|[1-9]\d*)$").unwrap().is_match(s) {
+        return Err(ParsePitchError);
     }
+
+    Ok(Pitch::from_midi(s.parse()?)?)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MidiFormat;
 
-    struct PitchClassCase {
+impl PitchNotation for MidiFormat {
+    fn name(&self) -> &'static str {
+        "midi"
+    }
+
+    fn priority(&self) -> i32 {
+        120
+    }
+
+    fn parse(&self, s: &str, options: &ParseOptions) -> Result<Pitch, ParsePitchError> {
+        parse_midi_note_number(&lenient::prepare(s, options, Recase::Keep))
+    }
+
+    fn format(&self, pitch: &Pitch, _: &FormatOptions) -> Result<String, FormatPitchError> {
+        Ok(pitch.to_midi()?.to_string())
+    }
+}
+
+fn parse_piano_key_number(s: &str) -> Result<Pitch, ParsePitchError> {
+    if !Regex::new(r"^[1-9]\d*$").unwrap().is_match(s) {
+        return Err(ParsePitchError);
+    }
+
+    Ok(Pitch::from_piano_key(s.parse()?)?)
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PianoKeyFormat;
+
+impl PitchNotation for PianoKeyFormat {
+    fn name(&self) -> &'static str {
+        "piano-key"
+    }
+
+    /// After MIDI, since key numbers 1-88 are also MIDI note numbers.
+    fn priority(&self) -> i32 {
+        130
+    }
+
+    fn parse(&self, s: &str, options: &ParseOptions) -> Result<Pitch, ParsePitchError> {
+        parse_piano_key_number(&lenient::prepare(s, options, Recase::Keep))
+    }
+
+    fn format(&self, pitch: &Pitch, _: &FormatOptions) -> Result<String, FormatPitchError> {
+        Ok(pitch.to_piano_key()?.to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScientificPitchNotation<'a> {
+    pitch: &'a Pitch,
+    accidental_style: AccidentalStyle,
+    middle_c: MiddleC,
+}
+
+impl ScientificPitchNotation<'_> {
+    pub fn accidental_style(mut self, accidental_style: AccidentalStyle) -> Self {
+        self.accidental_style = accidental_style;
+        self
+    }
+
+    pub fn middle_c(mut self, middle_c: MiddleC) -> Self {
+        self.middle_c = middle_c;
+        self
+    }
+}
+
+impl std::fmt::Display for ScientificPitchNotation<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let pitch_class = &self.pitch.pitch_class;
+
+        write!(
+            f,
+            "{}{}{}",
+            pitch_class.letter,
+            pitch_class.accidental.symbol(self.accidental_style),
+            self.pitch.octave as i32 - self.middle_c.octave_offset(),
+        )?;
+
+        fmt_cents(self.pitch.cents, f)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AlternativeStyle {
+    /// `lowlowG`, `hihiA`, `hihihihiC`
+    #[default]
+    Repeated,
+    /// `low2G`, `hi2A`, `hi4C`
+    Numeric,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlternativePitchNotation<'a> {
+    pitch: &'a Pitch,
+    accidental_style: AccidentalStyle,
+    style: AlternativeStyle,
+    octave_boundary: OctaveBoundary,
+}
+
+impl AlternativePitchNotation<'_> {
+    pub fn accidental_style(mut self, accidental_style: AccidentalStyle) -> Self {
+        self.accidental_style = accidental_style;
+        self
+    }
+
+    /// Writes two or more `low`/`hi` words as a count, e.g. `hi2A`.
+    pub fn style(mut self, style: AlternativeStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn octave_boundary(mut self, octave_boundary: OctaveBoundary) -> Self {
+        self.octave_boundary = octave_boundary;
+        self
+    }
+}
+
+impl std::fmt::Display for AlternativePitchNotation<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let pitch_class = &self.pitch.pitch_class;
+
+        let o = if self.octave_boundary.is_above(pitch_class) {
+            self.pitch.octave as i32 + 1
+        } else {
+            self.pitch.octave as i32
+        };
+
+        match (self.style, o) {
+            (AlternativeStyle::Numeric, n) if n < 2 => write!(f, "low{}", 3 - n)?,
+            (AlternativeStyle::Numeric, n) if n > 5 => write!(f, "hi{}", n - 4)?,
+            (_, n) if n < 3 => {
+                for _ in 0..3 - n {
+                    write!(f, "low")?;
+                }
+            }
+            (_, 3) => write!(f, "mid1")?,
+            (_, 4) => write!(f, "mid2")?,
+            (_, n) => {
+                for _ in 0..n - 4 {
+                    write!(f, "hi")?;
+                }
+            }
+        }
+
+        write!(
+            f,
+            "{}{}",
+            pitch_class.letter,
+            pitch_class.accidental.symbol(self.accidental_style),
+        )?;
+
+        fmt_cents(self.pitch.cents, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct PitchClassCase {
         pitch_class: PitchClass,
         s: &'static str,
     }
@@ -256,62 +1047,155 @@ mod tests {
         alternative_pitch_notation: &'static str,
     }
 
-    const PITCH_CLASS_CASES: [PitchClassCase; 12] = [
+    const PITCH_CLASS_CASES: [PitchClassCase; 7 * 5] = [
+        PitchClassCase {
+            pitch_class: PitchClass::new(Letter::C, Accidental::DoubleFlat),
+            s: "Cbb",
+        },
         PitchClassCase {
-            pitch_class: PitchClass::C,
+            pitch_class: PitchClass::new(Letter::C, Accidental::Flat),
+            s: "Cb",
+        },
+        PitchClassCase {
+            pitch_class: PitchClass::new(Letter::C, Accidental::Natural),
             s: "C",
         },
         PitchClassCase {
-            pitch_class: PitchClass::CSharp,
+            pitch_class: PitchClass::new(Letter::C, Accidental::Sharp),
             s: "C#",
         },
         PitchClassCase {
-            pitch_class: PitchClass::D,
+            pitch_class: PitchClass::new(Letter::C, Accidental::DoubleSharp),
+            s: "C##",
+        },
+        PitchClassCase {
+            pitch_class: PitchClass::new(Letter::D, Accidental::DoubleFlat),
+            s: "Dbb",
+        },
+        PitchClassCase {
+            pitch_class: PitchClass::new(Letter::D, Accidental::Flat),
+            s: "Db",
+        },
+        PitchClassCase {
+            pitch_class: PitchClass::new(Letter::D, Accidental::Natural),
             s: "D",
         },
         PitchClassCase {
-            pitch_class: PitchClass::DSharp,
+            pitch_class: PitchClass::new(Letter::D, Accidental::Sharp),
             s: "D#",
         },
         PitchClassCase {
-            pitch_class: PitchClass::E,
+            pitch_class: PitchClass::new(Letter::D, Accidental::DoubleSharp),
+            s: "D##",
+        },
+        PitchClassCase {
+            pitch_class: PitchClass::new(Letter::E, Accidental::DoubleFlat),
+            s: "Ebb",
+        },
+        PitchClassCase {
+            pitch_class: PitchClass::new(Letter::E, Accidental::Flat),
+            s: "Eb",
+        },
+        PitchClassCase {
+            pitch_class: PitchClass::new(Letter::E, Accidental::Natural),
             s: "E",
         },
         PitchClassCase {
-            pitch_class: PitchClass::F,
+            pitch_class: PitchClass::new(Letter::E, Accidental::Sharp),
+            s: "E#",
+        },
+        PitchClassCase {
+            pitch_class: PitchClass::new(Letter::E, Accidental::DoubleSharp),
+            s: "E##",
+        },
+        PitchClassCase {
+            pitch_class: PitchClass::new(Letter::F, Accidental::DoubleFlat),
+            s: "Fbb",
+        },
+        PitchClassCase {
+            pitch_class: PitchClass::new(Letter::F, Accidental::Flat),
+            s: "Fb",
+        },
+        PitchClassCase {
+            pitch_class: PitchClass::new(Letter::F, Accidental::Natural),
             s: "F",
         },
         PitchClassCase {
-            pitch_class: PitchClass::FSharp,
+            pitch_class: PitchClass::new(Letter::F, Accidental::Sharp),
             s: "F#",
         },
         PitchClassCase {
-            pitch_class: PitchClass::G,
+            pitch_class: PitchClass::new(Letter::F, Accidental::DoubleSharp),
+            s: "F##",
+        },
+        PitchClassCase {
+            pitch_class: PitchClass::new(Letter::G, Accidental::DoubleFlat),
+            s: "Gbb",
+        },
+        PitchClassCase {
+            pitch_class: PitchClass::new(Letter::G, Accidental::Flat),
+            s: "Gb",
+        },
+        PitchClassCase {
+            pitch_class: PitchClass::new(Letter::G, Accidental::Natural),
             s: "G",
         },
         PitchClassCase {
-            pitch_class: PitchClass::GSharp,
+            pitch_class: PitchClass::new(Letter::G, Accidental::Sharp),
             s: "G#",
         },
         PitchClassCase {
-            pitch_class: PitchClass::A,
+            pitch_class: PitchClass::new(Letter::G, Accidental::DoubleSharp),
+            s: "G##",
+        },
+        PitchClassCase {
+            pitch_class: PitchClass::new(Letter::A, Accidental::DoubleFlat),
+            s: "Abb",
+        },
+        PitchClassCase {
+            pitch_class: PitchClass::new(Letter::A, Accidental::Flat),
+            s: "Ab",
+        },
+        PitchClassCase {
+            pitch_class: PitchClass::new(Letter::A, Accidental::Natural),
             s: "A",
         },
         PitchClassCase {
-            pitch_class: PitchClass::ASharp,
+            pitch_class: PitchClass::new(Letter::A, Accidental::Sharp),
             s: "A#",
         },
         PitchClassCase {
-            pitch_class: PitchClass::B,
+            pitch_class: PitchClass::new(Letter::A, Accidental::DoubleSharp),
+            s: "A##",
+        },
+        PitchClassCase {
+            pitch_class: PitchClass::new(Letter::B, Accidental::DoubleFlat),
+            s: "Bbb",
+        },
+        PitchClassCase {
+            pitch_class: PitchClass::new(Letter::B, Accidental::Flat),
+            s: "Bb",
+        },
+        PitchClassCase {
+            pitch_class: PitchClass::new(Letter::B, Accidental::Natural),
             s: "B",
         },
+        PitchClassCase {
+            pitch_class: PitchClass::new(Letter::B, Accidental::Sharp),
+            s: "B#",
+        },
+        PitchClassCase {
+            pitch_class: PitchClass::new(Letter::B, Accidental::DoubleSharp),
+            s: "B##",
+        },
     ];
 
     const PITCH_CASES: [PitchCase; 12 * 10] = [
         PitchCase {
             pitch: Pitch {
                 octave: 0,
-                pitch_class: PitchClass::C,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "C0",
             alternative_pitch_notation: "lowlowlowC",
@@ -319,7 +1203,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 0,
-                pitch_class: PitchClass::CSharp,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "C#0",
             alternative_pitch_notation: "lowlowlowC#",
@@ -327,7 +1212,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 0,
-                pitch_class: PitchClass::D,
+                pitch_class: PitchClass::new(Letter::D, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "D0",
             alternative_pitch_notation: "lowlowlowD",
@@ -335,7 +1221,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 0,
-                pitch_class: PitchClass::DSharp,
+                pitch_class: PitchClass::new(Letter::D, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "D#0",
             alternative_pitch_notation: "lowlowlowD#",
@@ -343,7 +1230,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 0,
-                pitch_class: PitchClass::E,
+                pitch_class: PitchClass::new(Letter::E, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "E0",
             alternative_pitch_notation: "lowlowlowE",
@@ -351,7 +1239,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 0,
-                pitch_class: PitchClass::F,
+                pitch_class: PitchClass::new(Letter::F, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "F0",
             alternative_pitch_notation: "lowlowlowF",
@@ -359,7 +1248,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 0,
-                pitch_class: PitchClass::FSharp,
+                pitch_class: PitchClass::new(Letter::F, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "F#0",
             alternative_pitch_notation: "lowlowlowF#",
@@ -367,7 +1257,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 0,
-                pitch_class: PitchClass::G,
+                pitch_class: PitchClass::new(Letter::G, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "G0",
             alternative_pitch_notation: "lowlowlowG",
@@ -375,7 +1266,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 0,
-                pitch_class: PitchClass::GSharp,
+                pitch_class: PitchClass::new(Letter::G, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "G#0",
             alternative_pitch_notation: "lowlowlowG#",
@@ -383,7 +1275,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 0,
-                pitch_class: PitchClass::A,
+                pitch_class: PitchClass::new(Letter::A, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "A0",
             alternative_pitch_notation: "lowlowA",
@@ -391,7 +1284,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 0,
-                pitch_class: PitchClass::ASharp,
+                pitch_class: PitchClass::new(Letter::A, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "A#0",
             alternative_pitch_notation: "lowlowA#",
@@ -399,7 +1293,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 0,
-                pitch_class: PitchClass::B,
+                pitch_class: PitchClass::new(Letter::B, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "B0",
             alternative_pitch_notation: "lowlowB",
@@ -407,7 +1302,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 1,
-                pitch_class: PitchClass::C,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "C1",
             alternative_pitch_notation: "lowlowC",
@@ -415,7 +1311,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 1,
-                pitch_class: PitchClass::CSharp,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "C#1",
             alternative_pitch_notation: "lowlowC#",
@@ -423,7 +1320,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 1,
-                pitch_class: PitchClass::D,
+                pitch_class: PitchClass::new(Letter::D, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "D1",
             alternative_pitch_notation: "lowlowD",
@@ -431,7 +1329,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 1,
-                pitch_class: PitchClass::DSharp,
+                pitch_class: PitchClass::new(Letter::D, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "D#1",
             alternative_pitch_notation: "lowlowD#",
@@ -439,7 +1338,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 1,
-                pitch_class: PitchClass::E,
+                pitch_class: PitchClass::new(Letter::E, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "E1",
             alternative_pitch_notation: "lowlowE",
@@ -447,7 +1347,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 1,
-                pitch_class: PitchClass::F,
+                pitch_class: PitchClass::new(Letter::F, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "F1",
             alternative_pitch_notation: "lowlowF",
@@ -455,7 +1356,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 1,
-                pitch_class: PitchClass::FSharp,
+                pitch_class: PitchClass::new(Letter::F, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "F#1",
             alternative_pitch_notation: "lowlowF#",
@@ -463,7 +1365,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 1,
-                pitch_class: PitchClass::G,
+                pitch_class: PitchClass::new(Letter::G, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "G1",
             alternative_pitch_notation: "lowlowG",
@@ -471,7 +1374,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 1,
-                pitch_class: PitchClass::GSharp,
+                pitch_class: PitchClass::new(Letter::G, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "G#1",
             alternative_pitch_notation: "lowlowG#",
@@ -479,7 +1383,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 1,
-                pitch_class: PitchClass::A,
+                pitch_class: PitchClass::new(Letter::A, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "A1",
             alternative_pitch_notation: "lowA",
@@ -487,7 +1392,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 1,
-                pitch_class: PitchClass::ASharp,
+                pitch_class: PitchClass::new(Letter::A, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "A#1",
             alternative_pitch_notation: "lowA#",
@@ -495,7 +1401,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 1,
-                pitch_class: PitchClass::B,
+                pitch_class: PitchClass::new(Letter::B, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "B1",
             alternative_pitch_notation: "lowB",
@@ -503,7 +1410,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 2,
-                pitch_class: PitchClass::C,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "C2",
             alternative_pitch_notation: "lowC",
@@ -511,7 +1419,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 2,
-                pitch_class: PitchClass::CSharp,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "C#2",
             alternative_pitch_notation: "lowC#",
@@ -519,7 +1428,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 2,
-                pitch_class: PitchClass::D,
+                pitch_class: PitchClass::new(Letter::D, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "D2",
             alternative_pitch_notation: "lowD",
@@ -527,7 +1437,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 2,
-                pitch_class: PitchClass::DSharp,
+                pitch_class: PitchClass::new(Letter::D, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "D#2",
             alternative_pitch_notation: "lowD#",
@@ -535,7 +1446,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 2,
-                pitch_class: PitchClass::E,
+                pitch_class: PitchClass::new(Letter::E, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "E2",
             alternative_pitch_notation: "lowE",
@@ -543,7 +1455,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 2,
-                pitch_class: PitchClass::F,
+                pitch_class: PitchClass::new(Letter::F, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "F2",
             alternative_pitch_notation: "lowF",
@@ -551,7 +1464,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 2,
-                pitch_class: PitchClass::FSharp,
+                pitch_class: PitchClass::new(Letter::F, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "F#2",
             alternative_pitch_notation: "lowF#",
@@ -559,7 +1473,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 2,
-                pitch_class: PitchClass::G,
+                pitch_class: PitchClass::new(Letter::G, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "G2",
             alternative_pitch_notation: "lowG",
@@ -567,7 +1482,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 2,
-                pitch_class: PitchClass::GSharp,
+                pitch_class: PitchClass::new(Letter::G, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "G#2",
             alternative_pitch_notation: "lowG#",
@@ -575,7 +1491,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 2,
-                pitch_class: PitchClass::A,
+                pitch_class: PitchClass::new(Letter::A, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "A2",
             alternative_pitch_notation: "mid1A",
@@ -583,7 +1500,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 2,
-                pitch_class: PitchClass::ASharp,
+                pitch_class: PitchClass::new(Letter::A, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "A#2",
             alternative_pitch_notation: "mid1A#",
@@ -591,7 +1509,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 2,
-                pitch_class: PitchClass::B,
+                pitch_class: PitchClass::new(Letter::B, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "B2",
             alternative_pitch_notation: "mid1B",
@@ -599,7 +1518,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 3,
-                pitch_class: PitchClass::C,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "C3",
             alternative_pitch_notation: "mid1C",
@@ -607,7 +1527,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 3,
-                pitch_class: PitchClass::CSharp,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "C#3",
             alternative_pitch_notation: "mid1C#",
@@ -615,7 +1536,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 3,
-                pitch_class: PitchClass::D,
+                pitch_class: PitchClass::new(Letter::D, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "D3",
             alternative_pitch_notation: "mid1D",
@@ -623,7 +1545,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 3,
-                pitch_class: PitchClass::DSharp,
+                pitch_class: PitchClass::new(Letter::D, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "D#3",
             alternative_pitch_notation: "mid1D#",
@@ -631,7 +1554,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 3,
-                pitch_class: PitchClass::E,
+                pitch_class: PitchClass::new(Letter::E, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "E3",
             alternative_pitch_notation: "mid1E",
@@ -639,7 +1563,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 3,
-                pitch_class: PitchClass::F,
+                pitch_class: PitchClass::new(Letter::F, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "F3",
             alternative_pitch_notation: "mid1F",
@@ -647,7 +1572,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 3,
-                pitch_class: PitchClass::FSharp,
+                pitch_class: PitchClass::new(Letter::F, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "F#3",
             alternative_pitch_notation: "mid1F#",
@@ -655,7 +1581,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 3,
-                pitch_class: PitchClass::G,
+                pitch_class: PitchClass::new(Letter::G, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "G3",
             alternative_pitch_notation: "mid1G",
@@ -663,7 +1590,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 3,
-                pitch_class: PitchClass::GSharp,
+                pitch_class: PitchClass::new(Letter::G, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "G#3",
             alternative_pitch_notation: "mid1G#",
@@ -671,7 +1599,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 3,
-                pitch_class: PitchClass::A,
+                pitch_class: PitchClass::new(Letter::A, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "A3",
             alternative_pitch_notation: "mid2A",
@@ -679,7 +1608,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 3,
-                pitch_class: PitchClass::ASharp,
+                pitch_class: PitchClass::new(Letter::A, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "A#3",
             alternative_pitch_notation: "mid2A#",
@@ -687,7 +1617,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 3,
-                pitch_class: PitchClass::B,
+                pitch_class: PitchClass::new(Letter::B, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "B3",
             alternative_pitch_notation: "mid2B",
@@ -695,7 +1626,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 4,
-                pitch_class: PitchClass::C,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "C4",
             alternative_pitch_notation: "mid2C",
@@ -703,7 +1635,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 4,
-                pitch_class: PitchClass::CSharp,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "C#4",
             alternative_pitch_notation: "mid2C#",
@@ -711,7 +1644,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 4,
-                pitch_class: PitchClass::D,
+                pitch_class: PitchClass::new(Letter::D, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "D4",
             alternative_pitch_notation: "mid2D",
@@ -719,7 +1653,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 4,
-                pitch_class: PitchClass::DSharp,
+                pitch_class: PitchClass::new(Letter::D, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "D#4",
             alternative_pitch_notation: "mid2D#",
@@ -727,7 +1662,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 4,
-                pitch_class: PitchClass::E,
+                pitch_class: PitchClass::new(Letter::E, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "E4",
             alternative_pitch_notation: "mid2E",
@@ -735,7 +1671,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 4,
-                pitch_class: PitchClass::F,
+                pitch_class: PitchClass::new(Letter::F, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "F4",
             alternative_pitch_notation: "mid2F",
@@ -743,7 +1680,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 4,
-                pitch_class: PitchClass::FSharp,
+                pitch_class: PitchClass::new(Letter::F, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "F#4",
             alternative_pitch_notation: "mid2F#",
@@ -751,7 +1689,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 4,
-                pitch_class: PitchClass::G,
+                pitch_class: PitchClass::new(Letter::G, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "G4",
             alternative_pitch_notation: "mid2G",
@@ -759,7 +1698,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 4,
-                pitch_class: PitchClass::GSharp,
+                pitch_class: PitchClass::new(Letter::G, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "G#4",
             alternative_pitch_notation: "mid2G#",
@@ -767,7 +1707,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 4,
-                pitch_class: PitchClass::A,
+                pitch_class: PitchClass::new(Letter::A, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "A4",
             alternative_pitch_notation: "hiA",
@@ -775,7 +1716,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 4,
-                pitch_class: PitchClass::ASharp,
+                pitch_class: PitchClass::new(Letter::A, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "A#4",
             alternative_pitch_notation: "hiA#",
@@ -783,7 +1725,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 4,
-                pitch_class: PitchClass::B,
+                pitch_class: PitchClass::new(Letter::B, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "B4",
             alternative_pitch_notation: "hiB",
@@ -791,7 +1734,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 5,
-                pitch_class: PitchClass::C,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "C5",
             alternative_pitch_notation: "hiC",
@@ -799,7 +1743,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 5,
-                pitch_class: PitchClass::CSharp,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "C#5",
             alternative_pitch_notation: "hiC#",
@@ -807,7 +1752,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 5,
-                pitch_class: PitchClass::D,
+                pitch_class: PitchClass::new(Letter::D, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "D5",
             alternative_pitch_notation: "hiD",
@@ -815,7 +1761,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 5,
-                pitch_class: PitchClass::DSharp,
+                pitch_class: PitchClass::new(Letter::D, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "D#5",
             alternative_pitch_notation: "hiD#",
@@ -823,7 +1770,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 5,
-                pitch_class: PitchClass::E,
+                pitch_class: PitchClass::new(Letter::E, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "E5",
             alternative_pitch_notation: "hiE",
@@ -831,7 +1779,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 5,
-                pitch_class: PitchClass::F,
+                pitch_class: PitchClass::new(Letter::F, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "F5",
             alternative_pitch_notation: "hiF",
@@ -839,7 +1788,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 5,
-                pitch_class: PitchClass::FSharp,
+                pitch_class: PitchClass::new(Letter::F, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "F#5",
             alternative_pitch_notation: "hiF#",
@@ -847,7 +1797,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 5,
-                pitch_class: PitchClass::G,
+                pitch_class: PitchClass::new(Letter::G, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "G5",
             alternative_pitch_notation: "hiG",
@@ -855,7 +1806,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 5,
-                pitch_class: PitchClass::GSharp,
+                pitch_class: PitchClass::new(Letter::G, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "G#5",
             alternative_pitch_notation: "hiG#",
@@ -863,7 +1815,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 5,
-                pitch_class: PitchClass::A,
+                pitch_class: PitchClass::new(Letter::A, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "A5",
             alternative_pitch_notation: "hihiA",
@@ -871,7 +1824,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 5,
-                pitch_class: PitchClass::ASharp,
+                pitch_class: PitchClass::new(Letter::A, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "A#5",
             alternative_pitch_notation: "hihiA#",
@@ -879,7 +1833,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 5,
-                pitch_class: PitchClass::B,
+                pitch_class: PitchClass::new(Letter::B, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "B5",
             alternative_pitch_notation: "hihiB",
@@ -887,7 +1842,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 6,
-                pitch_class: PitchClass::C,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "C6",
             alternative_pitch_notation: "hihiC",
@@ -895,7 +1851,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 6,
-                pitch_class: PitchClass::CSharp,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "C#6",
             alternative_pitch_notation: "hihiC#",
@@ -903,7 +1860,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 6,
-                pitch_class: PitchClass::D,
+                pitch_class: PitchClass::new(Letter::D, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "D6",
             alternative_pitch_notation: "hihiD",
@@ -911,7 +1869,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 6,
-                pitch_class: PitchClass::DSharp,
+                pitch_class: PitchClass::new(Letter::D, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "D#6",
             alternative_pitch_notation: "hihiD#",
@@ -919,7 +1878,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 6,
-                pitch_class: PitchClass::E,
+                pitch_class: PitchClass::new(Letter::E, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "E6",
             alternative_pitch_notation: "hihiE",
@@ -927,7 +1887,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 6,
-                pitch_class: PitchClass::F,
+                pitch_class: PitchClass::new(Letter::F, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "F6",
             alternative_pitch_notation: "hihiF",
@@ -935,7 +1896,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 6,
-                pitch_class: PitchClass::FSharp,
+                pitch_class: PitchClass::new(Letter::F, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "F#6",
             alternative_pitch_notation: "hihiF#",
@@ -943,7 +1905,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 6,
-                pitch_class: PitchClass::G,
+                pitch_class: PitchClass::new(Letter::G, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "G6",
             alternative_pitch_notation: "hihiG",
@@ -951,7 +1914,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 6,
-                pitch_class: PitchClass::GSharp,
+                pitch_class: PitchClass::new(Letter::G, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "G#6",
             alternative_pitch_notation: "hihiG#",
@@ -959,7 +1923,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 6,
-                pitch_class: PitchClass::A,
+                pitch_class: PitchClass::new(Letter::A, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "A6",
             alternative_pitch_notation: "hihihiA",
@@ -967,7 +1932,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 6,
-                pitch_class: PitchClass::ASharp,
+                pitch_class: PitchClass::new(Letter::A, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "A#6",
             alternative_pitch_notation: "hihihiA#",
@@ -975,7 +1941,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 6,
-                pitch_class: PitchClass::B,
+                pitch_class: PitchClass::new(Letter::B, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "B6",
             alternative_pitch_notation: "hihihiB",
@@ -983,7 +1950,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 6,
-                pitch_class: PitchClass::C,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "C6",
             alternative_pitch_notation: "hihiC",
@@ -991,7 +1959,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 7,
-                pitch_class: PitchClass::CSharp,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "C#7",
             alternative_pitch_notation: "hihihiC#",
@@ -999,7 +1968,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 7,
-                pitch_class: PitchClass::D,
+                pitch_class: PitchClass::new(Letter::D, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "D7",
             alternative_pitch_notation: "hihihiD",
@@ -1007,7 +1977,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 7,
-                pitch_class: PitchClass::DSharp,
+                pitch_class: PitchClass::new(Letter::D, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "D#7",
             alternative_pitch_notation: "hihihiD#",
@@ -1015,7 +1986,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 7,
-                pitch_class: PitchClass::E,
+                pitch_class: PitchClass::new(Letter::E, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "E7",
             alternative_pitch_notation: "hihihiE",
@@ -1023,7 +1995,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 7,
-                pitch_class: PitchClass::F,
+                pitch_class: PitchClass::new(Letter::F, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "F7",
             alternative_pitch_notation: "hihihiF",
@@ -1031,7 +2004,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 7,
-                pitch_class: PitchClass::FSharp,
+                pitch_class: PitchClass::new(Letter::F, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "F#7",
             alternative_pitch_notation: "hihihiF#",
@@ -1039,7 +2013,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 7,
-                pitch_class: PitchClass::G,
+                pitch_class: PitchClass::new(Letter::G, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "G7",
             alternative_pitch_notation: "hihihiG",
@@ -1047,7 +2022,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 7,
-                pitch_class: PitchClass::GSharp,
+                pitch_class: PitchClass::new(Letter::G, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "G#7",
             alternative_pitch_notation: "hihihiG#",
@@ -1055,7 +2031,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 7,
-                pitch_class: PitchClass::A,
+                pitch_class: PitchClass::new(Letter::A, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "A7",
             alternative_pitch_notation: "hihihihiA",
@@ -1063,7 +2040,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 7,
-                pitch_class: PitchClass::ASharp,
+                pitch_class: PitchClass::new(Letter::A, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "A#7",
             alternative_pitch_notation: "hihihihiA#",
@@ -1071,7 +2049,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 7,
-                pitch_class: PitchClass::B,
+                pitch_class: PitchClass::new(Letter::B, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "B7",
             alternative_pitch_notation: "hihihihiB",
@@ -1079,7 +2058,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 8,
-                pitch_class: PitchClass::C,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "C8",
             alternative_pitch_notation: "hihihihiC",
@@ -1087,7 +2067,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 8,
-                pitch_class: PitchClass::CSharp,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "C#8",
             alternative_pitch_notation: "hihihihiC#",
@@ -1095,7 +2076,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 8,
-                pitch_class: PitchClass::D,
+                pitch_class: PitchClass::new(Letter::D, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "D8",
             alternative_pitch_notation: "hihihihiD",
@@ -1103,7 +2085,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 8,
-                pitch_class: PitchClass::DSharp,
+                pitch_class: PitchClass::new(Letter::D, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "D#8",
             alternative_pitch_notation: "hihihihiD#",
@@ -1111,7 +2094,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 8,
-                pitch_class: PitchClass::E,
+                pitch_class: PitchClass::new(Letter::E, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "E8",
             alternative_pitch_notation: "hihihihiE",
@@ -1119,7 +2103,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 8,
-                pitch_class: PitchClass::F,
+                pitch_class: PitchClass::new(Letter::F, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "F8",
             alternative_pitch_notation: "hihihihiF",
@@ -1127,7 +2112,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 8,
-                pitch_class: PitchClass::FSharp,
+                pitch_class: PitchClass::new(Letter::F, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "F#8",
             alternative_pitch_notation: "hihihihiF#",
@@ -1135,7 +2121,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 8,
-                pitch_class: PitchClass::G,
+                pitch_class: PitchClass::new(Letter::G, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "G8",
             alternative_pitch_notation: "hihihihiG",
@@ -1143,7 +2130,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 8,
-                pitch_class: PitchClass::GSharp,
+                pitch_class: PitchClass::new(Letter::G, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "G#8",
             alternative_pitch_notation: "hihihihiG#",
@@ -1151,7 +2139,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 8,
-                pitch_class: PitchClass::A,
+                pitch_class: PitchClass::new(Letter::A, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "A8",
             alternative_pitch_notation: "hihihihihiA",
@@ -1159,7 +2148,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 8,
-                pitch_class: PitchClass::ASharp,
+                pitch_class: PitchClass::new(Letter::A, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "A#8",
             alternative_pitch_notation: "hihihihihiA#",
@@ -1167,7 +2157,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 8,
-                pitch_class: PitchClass::B,
+                pitch_class: PitchClass::new(Letter::B, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "B8",
             alternative_pitch_notation: "hihihihihiB",
@@ -1175,7 +2166,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 255,
-                pitch_class: PitchClass::C,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "C255",
             alternative_pitch_notation: "hihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihiC",
@@ -1183,7 +2175,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 255,
-                pitch_class: PitchClass::CSharp,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "C#255",
             alternative_pitch_notation: "hihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihiC#",
@@ -1191,7 +2184,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 255,
-                pitch_class: PitchClass::D,
+                pitch_class: PitchClass::new(Letter::D, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "D255",
             alternative_pitch_notation: "hihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihiD",
@@ -1199,7 +2193,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 255,
-                pitch_class: PitchClass::DSharp,
+                pitch_class: PitchClass::new(Letter::D, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "D#255",
             alternative_pitch_notation: "hihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihiD#",
@@ -1207,7 +2202,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 255,
-                pitch_class: PitchClass::E,
+                pitch_class: PitchClass::new(Letter::E, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "E255",
             alternative_pitch_notation: "hihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihiE",
@@ -1215,7 +2211,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 255,
-                pitch_class: PitchClass::F,
+                pitch_class: PitchClass::new(Letter::F, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "F255",
             alternative_pitch_notation: "hihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihiF",
@@ -1223,7 +2220,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 255,
-                pitch_class: PitchClass::FSharp,
+                pitch_class: PitchClass::new(Letter::F, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "F#255",
             alternative_pitch_notation: "hihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihiF#",
@@ -1231,7 +2229,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 255,
-                pitch_class: PitchClass::G,
+                pitch_class: PitchClass::new(Letter::G, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "G255",
             alternative_pitch_notation: "hihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihiG",
@@ -1239,7 +2238,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 255,
-                pitch_class: PitchClass::GSharp,
+                pitch_class: PitchClass::new(Letter::G, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "G#255",
             alternative_pitch_notation: "hihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihiG#",
@@ -1247,7 +2247,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 255,
-                pitch_class: PitchClass::A,
+                pitch_class: PitchClass::new(Letter::A, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "A255",
             alternative_pitch_notation: "hihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihiA",
@@ -1255,7 +2256,8 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 255,
-                pitch_class: PitchClass::ASharp,
+                pitch_class: PitchClass::new(Letter::A, Accidental::Sharp),
+                cents: 0,
             },
             scientific_pitch_notation: "A#255",
             alternative_pitch_notation: "hihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihiA#",
@@ -1263,13 +2265,173 @@ mod tests {
         PitchCase {
             pitch: Pitch {
                 octave: 255,
-                pitch_class: PitchClass::B,
+                pitch_class: PitchClass::new(Letter::B, Accidental::Natural),
+                cents: 0,
             },
             scientific_pitch_notation: "B255",
             alternative_pitch_notation: "hihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihiB",
         },
     ];
 
+    const NEGATIVE_OCTAVE_PITCH_CASES: [PitchCase; 12] = [
+        PitchCase {
+            pitch: Pitch {
+                octave: -1,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Natural),
+                cents: 0,
+            },
+            scientific_pitch_notation: "C-1",
+            alternative_pitch_notation: "lowlowlowlowC",
+        },
+        PitchCase {
+            pitch: Pitch {
+                octave: -1,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Sharp),
+                cents: 0,
+            },
+            scientific_pitch_notation: "C#-1",
+            alternative_pitch_notation: "lowlowlowlowC#",
+        },
+        PitchCase {
+            pitch: Pitch {
+                octave: -1,
+                pitch_class: PitchClass::new(Letter::D, Accidental::Natural),
+                cents: 0,
+            },
+            scientific_pitch_notation: "D-1",
+            alternative_pitch_notation: "lowlowlowlowD",
+        },
+        PitchCase {
+            pitch: Pitch {
+                octave: -1,
+                pitch_class: PitchClass::new(Letter::D, Accidental::Sharp),
+                cents: 0,
+            },
+            scientific_pitch_notation: "D#-1",
+            alternative_pitch_notation: "lowlowlowlowD#",
+        },
+        PitchCase {
+            pitch: Pitch {
+                octave: -1,
+                pitch_class: PitchClass::new(Letter::E, Accidental::Natural),
+                cents: 0,
+            },
+            scientific_pitch_notation: "E-1",
+            alternative_pitch_notation: "lowlowlowlowE",
+        },
+        PitchCase {
+            pitch: Pitch {
+                octave: -1,
+                pitch_class: PitchClass::new(Letter::F, Accidental::Natural),
+                cents: 0,
+            },
+            scientific_pitch_notation: "F-1",
+            alternative_pitch_notation: "lowlowlowlowF",
+        },
+        PitchCase {
+            pitch: Pitch {
+                octave: -1,
+                pitch_class: PitchClass::new(Letter::F, Accidental::Sharp),
+                cents: 0,
+            },
+            scientific_pitch_notation: "F#-1",
+            alternative_pitch_notation: "lowlowlowlowF#",
+        },
+        PitchCase {
+            pitch: Pitch {
+                octave: -1,
+                pitch_class: PitchClass::new(Letter::G, Accidental::Natural),
+                cents: 0,
+            },
+            scientific_pitch_notation: "G-1",
+            alternative_pitch_notation: "lowlowlowlowG",
+        },
+        PitchCase {
+            pitch: Pitch {
+                octave: -1,
+                pitch_class: PitchClass::new(Letter::G, Accidental::Sharp),
+                cents: 0,
+            },
+            scientific_pitch_notation: "G#-1",
+            alternative_pitch_notation: "lowlowlowlowG#",
+        },
+        PitchCase {
+            pitch: Pitch {
+                octave: -1,
+                pitch_class: PitchClass::new(Letter::A, Accidental::Natural),
+                cents: 0,
+            },
+            scientific_pitch_notation: "A-1",
+            alternative_pitch_notation: "lowlowlowA",
+        },
+        PitchCase {
+            pitch: Pitch {
+                octave: -1,
+                pitch_class: PitchClass::new(Letter::A, Accidental::Sharp),
+                cents: 0,
+            },
+            scientific_pitch_notation: "A#-1",
+            alternative_pitch_notation: "lowlowlowA#",
+        },
+        PitchCase {
+            pitch: Pitch {
+                octave: -1,
+                pitch_class: PitchClass::new(Letter::B, Accidental::Natural),
+                cents: 0,
+            },
+            scientific_pitch_notation: "B-1",
+            alternative_pitch_notation: "lowlowlowB",
+        },
+    ];
+
+    const FLAT_PITCH_CASES: [PitchCase; 5] = [
+        PitchCase {
+            pitch: Pitch {
+                octave: 0,
+                pitch_class: PitchClass::new(Letter::B, Accidental::Flat),
+                cents: 0,
+            },
+            scientific_pitch_notation: "Bb0",
+            alternative_pitch_notation: "lowlowBb",
+        },
+        PitchCase {
+            pitch: Pitch {
+                octave: 3,
+                pitch_class: PitchClass::new(Letter::A, Accidental::Flat),
+                cents: 0,
+            },
+            scientific_pitch_notation: "Ab3",
+            alternative_pitch_notation: "mid2Ab",
+        },
+        PitchCase {
+            pitch: Pitch {
+                octave: 4,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Flat),
+                cents: 0,
+            },
+            scientific_pitch_notation: "Cb4",
+            alternative_pitch_notation: "mid2Cb",
+        },
+        PitchCase {
+            pitch: Pitch {
+                octave: 4,
+                pitch_class: PitchClass::new(Letter::D, Accidental::Flat),
+                cents: 0,
+            },
+            scientific_pitch_notation: "Db4",
+            alternative_pitch_notation: "mid2Db",
+        },
+        PitchCase {
+            pitch: Pitch {
+                octave: 5,
+                pitch_class: PitchClass::new(Letter::G, Accidental::Flat),
+                cents: 0,
+            },
+            scientific_pitch_notation: "Gb5",
+            alternative_pitch_notation: "hiGb",
+        },
+    ];
+
     #[test]
     fn test_parse_pitch_class() {
         for case in PITCH_CLASS_CASES {
@@ -1285,6 +2447,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_pitch_class_alternative_spellings() {
+        for (pitch_class, s) in [
+            (PitchClass::new(Letter::C, Accidental::DoubleSharp), "Cx"),
+            (PitchClass::new(Letter::C, Accidental::DoubleSharp), "C𝄪"),
+            (PitchClass::new(Letter::C, Accidental::DoubleSharp), "C♯♯"),
+            (PitchClass::new(Letter::F, Accidental::Sharp), "F♯"),
+            (PitchClass::new(Letter::B, Accidental::Flat), "B♭"),
+            (PitchClass::new(Letter::D, Accidental::DoubleFlat), "D𝄫"),
+            (PitchClass::new(Letter::D, Accidental::DoubleFlat), "D♭♭"),
+            (PitchClass::new(Letter::E, Accidental::Natural), "E♮"),
+        ] {
+            assert_eq!(Ok(pitch_class), parse_pitch_class(s));
+        }
+
+        assert_eq!(Err(ParsePitchClassError), parse_pitch_class("C###"));
+        assert_eq!(Err(ParsePitchClassError), parse_pitch_class("Cbbb"));
+        assert_eq!(Err(ParsePitchClassError), parse_pitch_class("C#b"));
+    }
+
     #[test]
     fn test_pitch_class_to_string() {
         for case in PITCH_CLASS_CASES {
@@ -1292,35 +2474,145 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_pitch_class_is_enharmonic_to() {
+        let c_sharp = PitchClass::new(Letter::C, Accidental::Sharp);
+        let d_flat = PitchClass::new(Letter::D, Accidental::Flat);
+        let b_sharp = PitchClass::new(Letter::B, Accidental::Sharp);
+        let c = PitchClass::new(Letter::C, Accidental::Natural);
+
+        assert!(c_sharp.is_enharmonic_to(&d_flat));
+        assert!(b_sharp.is_enharmonic_to(&c));
+        assert!(!c_sharp.is_enharmonic_to(&c));
+        assert_ne!(c_sharp, d_flat);
+    }
+
+    #[test]
+    fn test_pitch_class_spelling() {
+        for (s, sharp, flat) in [
+            ("C", "C", "C"),
+            ("C#", "C#", "Db"),
+            ("Db", "C#", "Db"),
+            ("D#", "D#", "Eb"),
+            ("E#", "F", "F"),
+            ("Fb", "E", "E"),
+            ("F#", "F#", "Gb"),
+            ("G#", "G#", "Ab"),
+            ("A#", "A#", "Bb"),
+            ("Cb", "B", "B"),
+            ("B#", "C", "C"),
+            ("Bbb", "A", "A"),
+            ("F##", "G", "G"),
+        ] {
+            let pitch_class: PitchClass = s.parse().unwrap();
+
+            assert_eq!(Ok(pitch_class.sharp_spelling()), sharp.parse());
+            assert_eq!(Ok(pitch_class.flat_spelling()), flat.parse());
+        }
+    }
+
+    #[test]
+    fn test_pitch_is_enharmonic_to() {
+        let b_sharp_3 = Pitch {
+            octave: 3,
+            pitch_class: PitchClass::new(Letter::B, Accidental::Sharp),
+            cents: 0,
+        };
+        let c_4 = Pitch {
+            octave: 4,
+            pitch_class: PitchClass::new(Letter::C, Accidental::Natural),
+            cents: 0,
+        };
+        let c_flat_4 = Pitch {
+            octave: 4,
+            pitch_class: PitchClass::new(Letter::C, Accidental::Flat),
+            cents: 0,
+        };
+        let b_3 = Pitch {
+            octave: 3,
+            pitch_class: PitchClass::new(Letter::B, Accidental::Natural),
+            cents: 0,
+        };
+
+        assert!(b_sharp_3.is_enharmonic_to(&c_4));
+        assert!(c_flat_4.is_enharmonic_to(&b_3));
+        assert!(!c_flat_4.is_enharmonic_to(&c_4));
+        assert_eq!(48, c_4.semitones());
+        assert_eq!(47, c_flat_4.semitones());
+    }
+
+    #[test]
+    fn test_pitch_ordering() {
+        let pitches: Vec<Pitch> = ["B3", "B#3", "Cb4", "C4", "C##4", "Db4", "D4"]
+            .into_iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        let mut sorted = pitches.clone();
+        sorted.sort();
+
+        assert_eq!(
+            vec!["B3", "Cb4", "B#3", "C4", "Db4", "C##4", "D4"],
+            sorted.iter().map(|p| p.to_string()).collect::<Vec<_>>(),
+        );
+        assert!("A4".parse::<Pitch>().unwrap() < "A4+10c".parse().unwrap());
+        assert!("A4+10c".parse::<Pitch>().unwrap() < "Bb4-10c".parse().unwrap());
+
+        let pitch_class = |s: &str| s.parse::<PitchClass>().unwrap();
+        assert!(pitch_class("Cb") < pitch_class("C"));
+        assert!(pitch_class("C##") > pitch_class("Db"));
+        assert!(pitch_class("B#") > pitch_class("B"));
+    }
+
     #[test]
     fn test_parse_scientific_pitch_notation() {
-        for case in PITCH_CASES {
+        for case in PITCH_CASES
+            .into_iter()
+            .chain(NEGATIVE_OCTAVE_PITCH_CASES)
+            .chain(FLAT_PITCH_CASES)
+        {
             assert_eq!(
                 Ok(case.pitch),
-                parse_scientific_pitch_notation(case.scientific_pitch_notation),
+                parse_scientific_pitch_notation(
+                    case.scientific_pitch_notation,
+                    &ParseOptions::default()
+                ),
             );
         }
 
         assert_eq!(
             Err(ParsePitchError),
-            parse_scientific_pitch_notation("invalid"),
+            parse_scientific_pitch_notation("invalid", &ParseOptions::default()),
+        );
+        assert_eq!(
+            Err(ParsePitchError),
+            parse_scientific_pitch_notation("B-256", &ParseOptions::default())
         );
-        assert_eq!(Err(ParsePitchError), parse_scientific_pitch_notation("B-1"));
         assert_eq!(
             Err(ParsePitchError),
-            parse_scientific_pitch_notation("C256"),
+            parse_scientific_pitch_notation("C-0", &ParseOptions::default())
+        );
+        assert_eq!(
+            Err(ParsePitchError),
+            parse_scientific_pitch_notation("C256", &ParseOptions::default()),
         );
         for case in PITCH_CASES {
             assert_eq!(
                 Err(ParsePitchError),
-                parse_scientific_pitch_notation(&case.scientific_pitch_notation.to_lowercase()),
+                parse_scientific_pitch_notation(
+                    &case.scientific_pitch_notation.to_lowercase(),
+                    &ParseOptions::default()
+                ),
             );
         }
     }
 
     #[test]
     fn test_scientific_pitch_notation_to_string() {
-        for case in PITCH_CASES {
+        for case in PITCH_CASES
+            .into_iter()
+            .chain(NEGATIVE_OCTAVE_PITCH_CASES)
+            .chain(FLAT_PITCH_CASES)
+        {
             assert_eq!(
                 case.scientific_pitch_notation,
                 case.pitch.scientific_pitch_notation().to_string(),
@@ -1329,37 +2621,932 @@ mod tests {
     }
 
     #[test]
-    fn test_parse_alternative_pitch_notation() {
+    fn test_scientific_pitch_notation_middle_c() {
+        let options = ParseOptions {
+            middle_c: MiddleC::C3,
+            ..Default::default()
+        };
+
+        for (scientific_pitch_notation, c3_scientific_pitch_notation) in [
+            ("C-1", "C-2"),
+            ("B-1", "B-2"),
+            ("C4", "C3"),
+            ("A4", "A3"),
+            ("C#255", "C#254"),
+        ] {
+            let pitch = parse_scientific_pitch_notation(
+                scientific_pitch_notation,
+                &ParseOptions::default(),
+            )
+            .unwrap();
+
+            assert_eq!(
+                Ok(pitch.clone()),
+                parse_scientific_pitch_notation(c3_scientific_pitch_notation, &options),
+            );
+            assert_eq!(
+                c3_scientific_pitch_notation,
+                pitch
+                    .scientific_pitch_notation()
+                    .middle_c(MiddleC::C3)
+                    .to_string(),
+            );
+        }
+
+        assert_eq!(
+            Err(ParsePitchError),
+            parse_scientific_pitch_notation("C-257", &options),
+        );
+        assert_eq!(
+            Err(ParsePitchError),
+            parse_scientific_pitch_notation("C255", &options),
+        );
+    }
+
+    #[test]
+    fn test_pitch_notation_with_cents() {
+        for (octave, pitch_class, cents, scientific_pitch_notation, alternative_pitch_notation) in [
+            (
+                4,
+                PitchClass::new(Letter::A, Accidental::Natural),
+                15,
+                "A4+15c",
+                "hiA+15c",
+            ),
+            (
+                4,
+                PitchClass::new(Letter::A, Accidental::Natural),
+                -30,
+                "A4-30c",
+                "hiA-30c",
+            ),
+            (
+                -1,
+                PitchClass::new(Letter::C, Accidental::Sharp),
+                -5,
+                "C#-1-5c",
+                "lowlowlowlowC#-5c",
+            ),
+            (
+                3,
+                PitchClass::new(Letter::B, Accidental::Flat),
+                99,
+                "Bb3+99c",
+                "mid2Bb+99c",
+            ),
+        ] {
+            let pitch = Pitch {
+                octave,
+                pitch_class,
+                cents,
+            };
+
+            assert_eq!(
+                Ok(pitch.clone()),
+                parse_scientific_pitch_notation(
+                    scientific_pitch_notation,
+                    &ParseOptions::default(),
+                ),
+            );
+            assert_eq!(
+                Ok(pitch.clone()),
+                parse_alternative_pitch_notation(
+                    alternative_pitch_notation,
+                    &ParseOptions::default()
+                ),
+            );
+            assert_eq!(
+                scientific_pitch_notation,
+                pitch.scientific_pitch_notation().to_string(),
+            );
+            assert_eq!(
+                alternative_pitch_notation,
+                pitch.alternative_pitch_notation().to_string(),
+            );
+        }
+
+        let a4 = parse_scientific_pitch_notation("A4", &ParseOptions::default()).unwrap();
+        assert_eq!(
+            Ok(a4.clone()),
+            parse_scientific_pitch_notation("A4+0c", &ParseOptions::default()),
+        );
+        assert_eq!(
+            "452.89Hz",
+            a4.clone()
+                .with_cents(50)
+                .unwrap()
+                .frequency(440.0)
+                .unwrap()
+                .to_string(),
+        );
+        assert_eq!(
+            Ok("A4-99c".to_owned()),
+            a4.clone().with_cents(-99).map(|pitch| pitch.to_string()),
+        );
+        assert_eq!(Err(PitchOutOfRangeError), a4.clone().with_cents(150));
+        assert_eq!(Err(PitchOutOfRangeError), a4.with_cents(-100));
+
+        for s in ["A4+100c", "A4+015c", "A4+c", "A4 +15c", "A4+15"] {
+            assert_eq!(
+                Err(ParsePitchError),
+                parse_scientific_pitch_notation(s, &ParseOptions::default()),
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_double_and_unicode_accidentals() {
+        let c_double_sharp_4 = Pitch {
+            octave: 4,
+            pitch_class: PitchClass::new(Letter::C, Accidental::DoubleSharp),
+            cents: 0,
+        };
+        let b_double_flat_3 = Pitch {
+            octave: 3,
+            pitch_class: PitchClass::new(Letter::B, Accidental::DoubleFlat),
+            cents: 0,
+        };
+
+        for s in ["C##4", "Cx4", "C𝄪4", "C♯♯4"] {
+            assert_eq!(
+                Ok(c_double_sharp_4.clone()),
+                parse_scientific_pitch_notation(s, &ParseOptions::default())
+            );
+        }
+        for s in ["mid2C##", "mid2Cx", "mid2C𝄪"] {
+            assert_eq!(
+                Ok(c_double_sharp_4.clone()),
+                parse_alternative_pitch_notation(s, &ParseOptions::default())
+            );
+        }
+        for s in ["Bbb3", "B𝄫3", "B♭♭3"] {
+            assert_eq!(
+                Ok(b_double_flat_3.clone()),
+                parse_scientific_pitch_notation(s, &ParseOptions::default())
+            );
+        }
+        for s in ["mid2Bbb", "mid2B𝄫"] {
+            assert_eq!(
+                Ok(b_double_flat_3.clone()),
+                parse_alternative_pitch_notation(s, &ParseOptions::default())
+            );
+        }
+    }
+
+    #[test]
+    fn test_pitch_notation_with_unicode_accidentals() {
+        for (pitch_class, scientific_pitch_notation, alternative_pitch_notation) in [
+            (
+                PitchClass::new(Letter::C, Accidental::Natural),
+                "C4",
+                "mid2C",
+            ),
+            (
+                PitchClass::new(Letter::F, Accidental::Sharp),
+                "F♯4",
+                "mid2F♯",
+            ),
+            (PitchClass::new(Letter::B, Accidental::Flat), "B♭4", "hiB♭"),
+            (
+                PitchClass::new(Letter::G, Accidental::DoubleSharp),
+                "G𝄪4",
+                "mid2G𝄪",
+            ),
+            (
+                PitchClass::new(Letter::A, Accidental::DoubleFlat),
+                "A𝄫4",
+                "hiA𝄫",
+            ),
+        ] {
+            let pitch = Pitch {
+                octave: 4,
+                pitch_class,
+                cents: 0,
+            };
+
+            assert_eq!(
+                scientific_pitch_notation,
+                pitch
+                    .scientific_pitch_notation()
+                    .accidental_style(AccidentalStyle::Unicode)
+                    .to_string(),
+            );
+            assert_eq!(
+                alternative_pitch_notation,
+                pitch
+                    .alternative_pitch_notation()
+                    .accidental_style(AccidentalStyle::Unicode)
+                    .to_string(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_semitones() {
+        for (semitones, scientific_pitch_notation) in [
+            (-12, "C-1"),
+            (-1, "B-1"),
+            (-3060, "C-255"),
+            (0, "C0"),
+            (49, "C#4"),
+            (3071, "B255"),
+        ] {
+            assert_eq!(
+                Ok(scientific_pitch_notation.parse::<Pitch>().unwrap()),
+                Pitch::from_semitones(semitones),
+            );
+        }
+
+        for semitones in [-3061, 3072, 1_000_000, i32::MIN] {
+            assert_eq!(Err(PitchOutOfRangeError), Pitch::from_semitones(semitones));
+        }
+
+        assert_eq!(Ok(-1), Pitch::checked_octave(-1));
+        assert_eq!(Ok(-255), Pitch::checked_octave(-255));
+        assert_eq!(Ok(255), Pitch::checked_octave(255));
+        assert_eq!(Err(ParsePitchError), Pitch::checked_octave(-256));
+        assert_eq!(Err(ParsePitchError), Pitch::checked_octave(256));
+    }
+
+    #[test]
+    fn test_midi_note_number() {
+        for (note_number, scientific_pitch_notation) in [
+            (0, "C-1"),
+            (11, "B-1"),
+            (12, "C0"),
+            (21, "A0"),
+            (60, "C4"),
+            (61, "C#4"),
+            (69, "A4"),
+            (127, "G9"),
+        ] {
+            let pitch = parse_scientific_pitch_notation(
+                scientific_pitch_notation,
+                &ParseOptions::default(),
+            )
+            .unwrap();
+
+            assert_eq!(Ok(pitch.clone()), Pitch::from_midi(note_number));
+            assert_eq!(Ok(pitch), parse_midi_note_number(&note_number.to_string()));
+        }
+
+        assert_eq!(Err(PitchOutOfRangeError), Pitch::from_midi(128));
+        assert_eq!(
+            Ok(61),
+            parse_scientific_pitch_notation("Db4", &ParseOptions::default())
+                .unwrap()
+                .to_midi(),
+        );
+        assert_eq!(
+            Ok(0),
+            parse_scientific_pitch_notation("Dbb-1", &ParseOptions::default())
+                .unwrap()
+                .to_midi(),
+        );
+        assert_eq!(
+            Err(PitchOutOfRangeError),
+            parse_scientific_pitch_notation("Cb-1", &ParseOptions::default())
+                .unwrap()
+                .to_midi(),
+        );
+        assert_eq!(
+            Err(PitchOutOfRangeError),
+            parse_scientific_pitch_notation("G#9", &ParseOptions::default())
+                .unwrap()
+                .to_midi(),
+        );
+
+        assert_eq!(Err(ParsePitchError), parse_midi_note_number("128"));
+        assert_eq!(Err(ParsePitchError), parse_midi_note_number("060"));
+        assert_eq!(Err(ParsePitchError), parse_midi_note_number("-1"));
+        assert_eq!(Err(ParsePitchError), parse_midi_note_number("invalid"));
+    }
+
+    #[test]
+    fn test_piano_key_number() {
+        for (key_number, scientific_pitch_notation) in [
+            (1, "A0"),
+            (3, "B0"),
+            (4, "C1"),
+            (40, "C4"),
+            (49, "A4"),
+            (87, "B7"),
+            (88, "C8"),
+        ] {
+            let pitch = parse_scientific_pitch_notation(
+                scientific_pitch_notation,
+                &ParseOptions::default(),
+            )
+            .unwrap();
+
+            assert_eq!(Ok(pitch.clone()), Pitch::from_piano_key(key_number));
+            assert_eq!(Ok(key_number), pitch.to_piano_key());
+            assert_eq!(Ok(pitch), parse_piano_key_number(&key_number.to_string()));
+        }
+
+        assert_eq!(Err(PitchOutOfRangeError), Pitch::from_piano_key(0));
+        assert_eq!(Err(PitchOutOfRangeError), Pitch::from_piano_key(89));
+        for scientific_pitch_notation in ["G#0", "Ab0", "C#8", "C-1", "G9"] {
+            assert_eq!(
+                Err(PitchOutOfRangeError),
+                parse_scientific_pitch_notation(
+                    scientific_pitch_notation,
+                    &ParseOptions::default(),
+                )
+                .unwrap()
+                .to_piano_key(),
+            );
+        }
+        assert_eq!(
+            Ok(2),
+            parse_scientific_pitch_notation("Bb0", &ParseOptions::default())
+                .unwrap()
+                .to_piano_key(),
+        );
+
+        for s in ["0", "89", "040", "-1", "invalid"] {
+            assert_eq!(Err(ParsePitchError), parse_piano_key_number(s));
+        }
+    }
+
+    #[test]
+    fn test_frequency() {
+        for (scientific_pitch_notation, a4_frequency, frequency) in [
+            ("A4", 440.0, "440.00Hz"),
+            ("A4", 442.0, "442.00Hz"),
+            ("A4", 415.0, "415.00Hz"),
+            ("C4", 440.0, "261.63Hz"),
+            ("A0", 440.0, "27.50Hz"),
+            ("Bb3", 440.0, "233.08Hz"),
+        ] {
+            let pitch = parse_scientific_pitch_notation(
+                scientific_pitch_notation,
+                &ParseOptions::default(),
+            )
+            .unwrap();
+
+            assert_eq!(
+                frequency,
+                pitch.frequency(a4_frequency).unwrap().to_string()
+            );
+            assert_eq!(
+                Ok(Pitch::from_semitones(pitch.semitones()).unwrap()),
+                Pitch::from_frequency(frequency.parse().unwrap(), a4_frequency),
+            );
+        }
+
+        let a4 = parse_scientific_pitch_notation("A4", &ParseOptions::default()).unwrap();
+        assert_eq!(
+            Ok(Pitch {
+                cents: 8,
+                ..a4.clone()
+            }),
+            Pitch::from_frequency(Frequency::new(442.0).unwrap(), 440.0),
+        );
+        assert_eq!(
+            Ok(Pitch {
+                cents: -30,
+                ..a4.clone()
+            }),
+            Pitch::from_frequency(Frequency::new(432.4).unwrap(), 440.0),
+        );
+        assert_eq!(
+            Err(ParsePitchError),
+            Pitch::from_frequency(Frequency::new(1e-80).unwrap(), 440.0),
+        );
+
+        for a4_frequency in [0.0, -5.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(
+                Err(ParsePitchError),
+                Pitch::from_frequency(Frequency::new(440.0).unwrap(), a4_frequency),
+            );
+            assert_eq!(Err(InvalidFrequencyError), a4.frequency(a4_frequency));
+        }
+        assert_eq!(Err(InvalidFrequencyError), Frequency::new(f64::NAN));
+        assert_eq!(
+            Err(InvalidFrequencyError),
+            "C255".parse::<Pitch>().unwrap().frequency(1e308),
+        );
+        assert_eq!(
+            Err(ParsePitchError),
+            PitchFormat::of(&FrequencyFormat).parse(
+                "440Hz",
+                &ParseOptions {
+                    a4_frequency: -5.0,
+                    ..Default::default()
+                },
+            ),
+        );
+
+        assert_eq!(Ok(Frequency::new(440.0).unwrap()), "440Hz".parse());
+        assert_eq!(Ok(Frequency::new(261.63).unwrap()), "261.63 Hz".parse());
+        assert_eq!(Err(ParsePitchError), "0Hz".parse::<Frequency>());
+        assert_eq!(Err(ParsePitchError), "440".parse::<Frequency>());
+        assert_eq!(Err(ParsePitchError), ".5Hz".parse::<Frequency>());
+    }
+
+    #[test]
+    fn test_parse_pitch_with_format() {
+        assert_eq!(
+            Ok(PitchFormat::of(&ScientificFormat)),
+            "C4".parse::<PitchWithFormat>().map(|p| p.format),
+        );
+        assert_eq!(
+            Ok(PitchFormat::of(&AlternativeFormat)),
+            "mid2C".parse::<PitchWithFormat>().map(|p| p.format),
+        );
+        assert_eq!(
+            Ok(PitchFormat::of(&HelmholtzFormat)),
+            "c'".parse::<PitchWithFormat>().map(|p| p.format),
+        );
+        assert_eq!(
+            Ok(PitchFormat::of(&LilyPondFormat)),
+            "cis''".parse::<PitchWithFormat>().map(|p| p.format),
+        );
+        assert_eq!(
+            Ok(PitchFormat::of(&AbcFormat)),
+            "^f".parse::<PitchWithFormat>().map(|p| p.format),
+        );
+        assert_eq!(
+            Ok(PitchFormat::of(&AbcFormat)),
+            "_B,".parse::<PitchWithFormat>().map(|p| p.format),
+        );
+        assert_eq!(
+            Ok(PitchFormat::of(&GermanFormat)),
+            "Cis4".parse::<PitchWithFormat>().map(|p| p.format),
+        );
+        assert_eq!(
+            Ok(PitchFormat::of(&JapaneseFormat)),
+            "一点ハ".parse::<PitchWithFormat>().map(|p| p.format),
+        );
+        assert_eq!(
+            Ok(PitchFormat::of(&KatakanaSolfegeFormat)),
+            "ド4".parse::<PitchWithFormat>().map(|p| p.format),
+        );
+        assert_eq!(
+            Ok(PitchFormat::of(&SolfegeFormat)),
+            "Sol#3".parse::<PitchWithFormat>().map(|p| p.format),
+        );
+        assert_eq!(
+            Ok(PitchFormat::of(&CsoundPchFormat)),
+            "8.00".parse::<PitchWithFormat>().map(|p| p.format),
+        );
+        assert_eq!(
+            Ok(PitchFormat::of(&CsoundOctFormat)),
+            "8.5".parse::<PitchWithFormat>().map(|p| p.format),
+        );
+        assert_eq!(
+            Ok(PitchFormat::of(&MidiFormat)),
+            "60".parse::<PitchWithFormat>().map(|p| p.format),
+        );
+        assert_eq!(
+            Ok(PitchFormat::of(&FrequencyFormat)),
+            "261.63Hz".parse::<PitchWithFormat>().map(|p| p.format),
+        );
+        assert_eq!(Err(ParsePitchError), "invalid".parse::<PitchWithFormat>());
+    }
+
+    #[test]
+    fn test_parse_all_pitch_with_format() {
+        let options = ParseOptions::default();
+
+        for (s, candidates) in [
+            (
+                "B3",
+                vec![
+                    (PitchFormat::of(&ScientificFormat), "B3"),
+                    (PitchFormat::of(&GermanFormat), "Bb3"),
+                ],
+            ),
+            (
+                "60",
+                vec![
+                    (PitchFormat::of(&MidiFormat), "C4"),
+                    (PitchFormat::of(&PianoKeyFormat), "G#5"),
+                ],
+            ),
+            (
+                "8.00",
+                vec![
+                    (PitchFormat::of(&CsoundPchFormat), "C4"),
+                    (PitchFormat::of(&CsoundOctFormat), "C4"),
+                ],
+            ),
+            (
+                "8.1",
+                vec![
+                    (PitchFormat::of(&CsoundPchFormat), "A#4"),
+                    (PitchFormat::of(&CsoundOctFormat), "C#4+20c"),
+                ],
+            ),
+            (
+                "C-1",
+                vec![
+                    (PitchFormat::of(&ScientificFormat), "C-1"),
+                    (PitchFormat::of(&GermanFormat), "C-1"),
+                    (PitchFormat::of(&TrackerFormat), "C1"),
+                ],
+            ),
+            (
+                "C-4",
+                vec![
+                    (PitchFormat::of(&ScientificFormat), "C-4"),
+                    (PitchFormat::of(&GermanFormat), "C-4"),
+                    (PitchFormat::of(&TrackerFormat), "C4"),
+                ],
+            ),
+            ("mid2C", vec![(PitchFormat::of(&AlternativeFormat), "C4")]),
+            ("invalid", vec![]),
+        ] {
+            let candidates: Vec<_> = candidates
+                .into_iter()
+                .map(|(format, pitch)| PitchWithFormat {
+                    pitch: pitch.parse().unwrap(),
+                    format,
+                })
+                .collect();
+
+            assert_eq!(candidates, PitchWithFormat::parse_all(s, &options));
+            assert_eq!(
+                candidates.first().cloned().ok_or(ParsePitchError),
+                PitchWithFormat::parse(s, &options),
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_pitch_with_format_preferred() {
+        let options = ParseOptions::default();
+
+        let mut german_first = NotationRegistry::default();
+        german_first.prefer("german");
+        assert_eq!(
+            Ok(PitchWithFormat {
+                pitch: "Bb3".parse().unwrap(),
+                format: PitchFormat::of(&GermanFormat),
+            }),
+            german_first.parse("B3", &options),
+        );
+        assert_eq!(
+            Ok(PitchFormat::of(&AlternativeFormat)),
+            german_first.parse("mid2C", &options).map(|p| p.format),
+        );
+
+        let mut tracker_first = NotationRegistry::default();
+        tracker_first.prefer("tracker");
+        assert_eq!(
+            Ok(PitchWithFormat {
+                pitch: "C4".parse().unwrap(),
+                format: PitchFormat::of(&TrackerFormat),
+            }),
+            tracker_first.parse("C-4", &options),
+        );
+
+        let mut piano_key_first = NotationRegistry::new();
+        piano_key_first.register(MidiFormat);
+        piano_key_first.register(PianoKeyFormat);
+        piano_key_first.prefer("piano-key");
+        assert_eq!(
+            Ok(PitchFormat::of(&PianoKeyFormat)),
+            piano_key_first.parse("40", &options).map(|p| p.format),
+        );
+        assert_eq!(
+            Ok(PitchFormat::of(&MidiFormat)),
+            piano_key_first.parse("100", &options).map(|p| p.format),
+        );
+        assert_eq!(
+            Err(ParsePitchError),
+            piano_key_first.parse("mid2C", &options)
+        );
+    }
+
+    #[test]
+    fn test_parse_pitch_with_format_normalizes_input() {
+        for (s, normalized) in [
+            ("ｍｉｄ２Ｃ", "mid2C"),
+            ("hiＡ＃", "hiA#"),
+            ("Ｃ４", "C4"),
+            ("\u{3000}C4 ", "C4"),
+            ("A4\u{2212}30c", "A4-30c"),
+            ("６０", "60"),
+        ] {
+            assert_eq!(normalized.parse::<PitchWithFormat>(), s.parse());
+        }
+
+        assert_eq!(
+            PitchFormat::of(&GermanFormat).parse("Cis4", &ParseOptions::default()),
+            PitchFormat::of(&GermanFormat).parse("Ｃｉｓ４", &ParseOptions::default()),
+        );
+
+        assert_eq!(Err(ParsePitchError), "ｃ４".parse::<PitchWithFormat>());
+    }
+
+    #[test]
+    fn test_parse_pitch_with_format_lenient() {
+        let options = ParseOptions {
+            lenient: true,
+            ..Default::default()
+        };
+
+        for (s, canonical) in [
+            ("c4", "C4"),
+            ("bb3", "Bb3"),
+            ("MID2C", "mid2C"),
+            ("mid 2 c", "mid2C"),
+            ("Hi A#", "hiA#"),
+            ("HIHIB", "hihiB"),
+            ("c '", "c'"),
+            ("CIS4", "Cis4"),
+            ("sol#3", "Sol#3"),
+            ("440 hz", "440Hz"),
+            ("ｃ４", "C4"),
+        ] {
+            assert_eq!(Err(ParsePitchError), s.parse::<PitchWithFormat>());
+            assert_eq!(canonical.parse(), PitchWithFormat::parse(s, &options));
+        }
+
+        // Input with a strict reading reads the same either way.
+        for s in ["c", "B", "cis", "60"] {
+            assert_eq!(
+                PitchWithFormat::parse_all(s, &ParseOptions::default()),
+                PitchWithFormat::parse_all(s, &options),
+            );
+        }
+
         for case in PITCH_CASES {
+            assert_eq!(
+                Ok(case.pitch.clone()),
+                PitchFormat::of(&ScientificFormat)
+                    .parse(&case.scientific_pitch_notation.to_lowercase(), &options),
+            );
+            assert_eq!(
+                Ok(case.pitch),
+                PitchFormat::of(&AlternativeFormat)
+                    .parse(&case.alternative_pitch_notation.to_uppercase(), &options),
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_alternative_pitch_notation() {
+        for case in PITCH_CASES
+            .into_iter()
+            .chain(NEGATIVE_OCTAVE_PITCH_CASES)
+            .chain(FLAT_PITCH_CASES)
+        {
             assert_eq!(
                 Ok(case.pitch),
-                parse_alternative_pitch_notation(case.alternative_pitch_notation),
+                parse_alternative_pitch_notation(
+                    case.alternative_pitch_notation,
+                    &ParseOptions::default()
+                ),
             );
         }
 
         assert_eq!(
             Err(ParsePitchError),
-            parse_alternative_pitch_notation("invalid"),
+            parse_alternative_pitch_notation("invalid", &ParseOptions::default()),
+        );
+        assert_eq!(
+            Err(ParsePitchError),
+            parse_alternative_pitch_notation("low258A", &ParseOptions::default()),
         );
         assert_eq!(
             Err(ParsePitchError),
-            parse_alternative_pitch_notation("hihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihiC"),
+            parse_alternative_pitch_notation("hihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihihiC", &ParseOptions::default()),
         );
         for case in PITCH_CASES {
             assert_eq!(
                 Err(ParsePitchError),
-                parse_alternative_pitch_notation(&case.alternative_pitch_notation.to_lowercase()),
+                parse_alternative_pitch_notation(
+                    &case.alternative_pitch_notation.to_lowercase(),
+                    &ParseOptions::default()
+                ),
             );
         }
     }
 
+    #[test]
+    fn test_alternative_pitch_notation_low_octaves() {
+        for (octave, letter, s) in [
+            (2, Letter::C, "lowC"),
+            (1, Letter::B, "lowB"),
+            (1, Letter::G, "lowlowG"),
+            (0, Letter::B, "lowlowB"),
+            (0, Letter::A, "lowlowA"),
+            (0, Letter::G, "lowlowlowG"),
+            (-1, Letter::B, "lowlowlowB"),
+            (-1, Letter::A, "lowlowlowA"),
+            (-1, Letter::G, "lowlowlowlowG"),
+            (-1, Letter::C, "lowlowlowlowC"),
+            (-2, Letter::B, "lowlowlowlowB"),
+            (-2, Letter::A, "lowlowlowlowA"),
+            (-2, Letter::C, "lowlowlowlowlowC"),
+        ] {
+            let pitch = Pitch {
+                octave,
+                pitch_class: PitchClass::new(letter, Accidental::Natural),
+                cents: 0,
+            };
+
+            assert_eq!(
+                Ok(pitch.clone()),
+                parse_alternative_pitch_notation(s, &ParseOptions::default())
+            );
+            assert_eq!(s, pitch.alternative_pitch_notation().to_string());
+        }
+
+        assert_eq!(
+            Pitch::checked_octave(Pitch::MIN_OCTAVE as i32).map(|octave| Pitch {
+                octave,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Natural),
+                cents: 0,
+            }),
+            parse_alternative_pitch_notation("low258C", &ParseOptions::default()),
+        );
+
+        // One more "low" goes below C-255, the lowest supported octave.
+        for s in ["low258A", "low258B", "low259C"] {
+            assert_eq!(
+                Err(ParsePitchError),
+                parse_alternative_pitch_notation(s, &ParseOptions::default())
+            );
+        }
+    }
+
+    #[test]
+    fn test_alternative_pitch_notation_octave_boundary() {
+        for (octave, pitch_class, a, c, g_sharp) in [
+            (
+                4,
+                PitchClass::new(Letter::C, Accidental::Natural),
+                "mid2C",
+                "mid2C",
+                "mid2C",
+            ),
+            (
+                4,
+                PitchClass::new(Letter::G, Accidental::Natural),
+                "mid2G",
+                "mid2G",
+                "mid2G",
+            ),
+            (
+                4,
+                PitchClass::new(Letter::G, Accidental::Sharp),
+                "mid2G#",
+                "mid2G#",
+                "hiG#",
+            ),
+            (
+                4,
+                PitchClass::new(Letter::A, Accidental::Flat),
+                "hiAb",
+                "mid2Ab",
+                "hiAb",
+            ),
+            (
+                4,
+                PitchClass::new(Letter::A, Accidental::Natural),
+                "hiA",
+                "mid2A",
+                "hiA",
+            ),
+            (
+                4,
+                PitchClass::new(Letter::B, Accidental::Natural),
+                "hiB",
+                "mid2B",
+                "hiB",
+            ),
+            (
+                1,
+                PitchClass::new(Letter::G, Accidental::Sharp),
+                "lowlowG#",
+                "lowlowG#",
+                "lowG#",
+            ),
+        ] {
+            let pitch = Pitch {
+                octave,
+                pitch_class,
+                cents: 0,
+            };
+
+            for (octave_boundary, s) in [
+                (OctaveBoundary::A, a),
+                (OctaveBoundary::C, c),
+                (OctaveBoundary::GSharp, g_sharp),
+            ] {
+                let options = ParseOptions {
+                    octave_boundary,
+                    ..Default::default()
+                };
+
+                assert_eq!(
+                    Ok(pitch.clone()),
+                    parse_alternative_pitch_notation(s, &options),
+                );
+                assert_eq!(
+                    s,
+                    pitch
+                        .alternative_pitch_notation()
+                        .octave_boundary(octave_boundary)
+                        .to_string(),
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_alternative_pitch_notation_to_string() {
-        for case in PITCH_CASES {
+        for case in PITCH_CASES
+            .into_iter()
+            .chain(NEGATIVE_OCTAVE_PITCH_CASES)
+            .chain(FLAT_PITCH_CASES)
+        {
             assert_eq!(
                 case.alternative_pitch_notation,
                 case.pitch.alternative_pitch_notation().to_string(),
             );
         }
     }
+
+    #[test]
+    fn test_parse_alternative_pitch_notation_aliases() {
+        for (alias, s) in [
+            ("hi1A", "hiA"),
+            ("hi2A", "hihiA"),
+            ("2hiA", "hihiA"),
+            ("hi3C#", "hihihiC#"),
+            ("3hiC#", "hihihiC#"),
+            ("m1G", "mid1G"),
+            ("m2G", "mid2G"),
+            ("low1G", "lowG"),
+            ("low2G", "lowlowG"),
+            ("low3Bb", "lowlowlowBb"),
+            ("low4C", "lowlowlowlowC"),
+            ("low4A", "lowlowlowlowA"),
+            ("hi2A-15c", "hihiA-15c"),
+        ] {
+            assert_eq!(
+                parse_alternative_pitch_notation(s, &ParseOptions::default()),
+                parse_alternative_pitch_notation(alias, &ParseOptions::default()),
+            );
+        }
+
+        for s in ["hi0A", "0hiA", "low0G", "m3G", "hi02A", "2hi2A"] {
+            assert_eq!(
+                Err(ParsePitchError),
+                parse_alternative_pitch_notation(s, &ParseOptions::default())
+            );
+        }
+    }
+
+    #[test]
+    fn test_alternative_pitch_notation_numeric_style() {
+        for case in PITCH_CASES
+            .into_iter()
+            .chain(NEGATIVE_OCTAVE_PITCH_CASES)
+            .chain(FLAT_PITCH_CASES)
+        {
+            let numeric = case
+                .pitch
+                .alternative_pitch_notation()
+                .style(AlternativeStyle::Numeric)
+                .to_string();
+
+            assert_eq!(
+                Ok(case.pitch),
+                parse_alternative_pitch_notation(&numeric, &ParseOptions::default())
+            );
+        }
+
+        for (octave, letter, numeric) in [
+            (-1, Letter::C, "low4C"),
+            (0, Letter::C, "low3C"),
+            (1, Letter::G, "low2G"),
+            (1, Letter::A, "lowA"),
+            (2, Letter::C, "lowC"),
+            (3, Letter::C, "mid1C"),
+            (4, Letter::G, "mid2G"),
+            (4, Letter::A, "hiA"),
+            (5, Letter::A, "hi2A"),
+            (8, Letter::C, "hi4C"),
+        ] {
+            let pitch = Pitch {
+                octave,
+                pitch_class: PitchClass::new(letter, Accidental::Natural),
+                cents: 0,
+            };
+
+            assert_eq!(
+                numeric,
+                pitch
+                    .alternative_pitch_notation()
+                    .style(AlternativeStyle::Numeric)
+                    .to_string(),
+            );
+        }
+    }
 }
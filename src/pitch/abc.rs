@@ -0,0 +1,170 @@
+use regex::Regex;
+
+use crate::error::{FormatPitchError, ParsePitchError};
+
+use super::{
+    lenient::{self, Recase},
+    Accidental, FormatOptions, ParseOptions, Pitch, PitchClass, PitchNotation,
+};
+
+/// Octave of an uppercase note such as `C` (middle C).
+const UPPERCASE_OCTAVE: i32 = 4;
+
+impl Pitch {
+    pub fn abc_notation(&self) -> AbcNotation<'_> {
+        AbcNotation { pitch: self }
+    }
+}
+
+fn parse_abc_notation(s: &str) -> Result<Pitch, ParsePitchError> {
+    let Some(caps) =
+        Regex::new(r"^(?<accidental>\^\^|\^|__|_|=)?(?<letter>[A-Ga-g])(?<marks>[',]*)$")
+            .unwrap()
+            .captures(s)
+    else {
+        return Err(ParsePitchError);
+    };
+
+    let letter = caps.name("letter").unwrap().as_str();
+    let accidental = match caps.name("accidental").map(|m| m.as_str()) {
+        Some("^^") => Accidental::DoubleSharp,
+        Some("^") => Accidental::Sharp,
+        Some("__") => Accidental::DoubleFlat,
+        Some("_") => Accidental::Flat,
+        _ => Accidental::Natural,
+    };
+    let pitch_class: PitchClass =
+        format!("{}{}", letter.to_ascii_uppercase(), accidental.as_str()).parse()?;
+
+    let base_octave = if letter.chars().all(|c| c.is_ascii_lowercase()) {
+        UPPERCASE_OCTAVE + 1
+    } else {
+        UPPERCASE_OCTAVE
+    };
+    let marks = caps.name("marks").unwrap().as_str();
+    let octave =
+        base_octave + marks.matches('\'').count() as i32 - marks.matches(',').count() as i32;
+
+    let octave = Pitch::checked_octave(octave)?;
+
+    Ok(Pitch {
+        octave,
+        pitch_class,
+        cents: 0,
+    })
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AbcFormat;
+
+impl PitchNotation for AbcFormat {
+    fn name(&self) -> &'static str {
+        "abc"
+    }
+
+    /// After Helmholtz, which reads plain letters such as `C` or `c'` an
+    /// octave or two lower.
+    fn priority(&self) -> i32 {
+        40
+    }
+
+    fn parse(&self, s: &str, options: &ParseOptions) -> Result<Pitch, ParsePitchError> {
+        parse_abc_notation(&lenient::prepare(s, options, Recase::Keep))
+    }
+
+    fn format(&self, pitch: &Pitch, _: &FormatOptions) -> Result<String, FormatPitchError> {
+        Ok(pitch.abc_notation().to_string())
+    }
+}
+
+/// ABC notation pitch such as `C`, `^f`, `_B,` or `c'`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbcNotation<'a> {
+    pitch: &'a Pitch,
+}
+
+impl std::fmt::Display for AbcNotation<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let pitch_class = &self.pitch.pitch_class;
+        let octave = self.pitch.octave as i32;
+
+        let accidental = match pitch_class.accidental {
+            Accidental::DoubleFlat => "__",
+            Accidental::Flat => "_",
+            Accidental::Natural => "",
+            Accidental::Sharp => "^",
+            Accidental::DoubleSharp => "^^",
+        };
+
+        if octave > UPPERCASE_OCTAVE {
+            let letter = pitch_class.letter.as_str().to_ascii_lowercase();
+
+            write!(f, "{accidental}{letter}")?;
+            for _ in UPPERCASE_OCTAVE + 1..octave {
+                write!(f, "'")?;
+            }
+        } else {
+            write!(f, "{accidental}{}", pitch_class.letter)?;
+            for _ in octave..UPPERCASE_OCTAVE {
+                write!(f, ",")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pitch::Letter;
+
+    #[test]
+    fn test_abc_notation() {
+        for (octave, pitch_class, s) in [
+            (
+                -1,
+                PitchClass::new(Letter::C, Accidental::Natural),
+                "C,,,,,",
+            ),
+            (2, PitchClass::new(Letter::B, Accidental::Flat), "_B,,"),
+            (3, PitchClass::new(Letter::G, Accidental::Natural), "G,"),
+            (4, PitchClass::new(Letter::C, Accidental::Natural), "C"),
+            (4, PitchClass::new(Letter::F, Accidental::Sharp), "^F"),
+            (4, PitchClass::new(Letter::B, Accidental::Natural), "B"),
+            (5, PitchClass::new(Letter::C, Accidental::Natural), "c"),
+            (5, PitchClass::new(Letter::E, Accidental::DoubleFlat), "__e"),
+            (
+                6,
+                PitchClass::new(Letter::C, Accidental::DoubleSharp),
+                "^^c'",
+            ),
+            (7, PitchClass::new(Letter::A, Accidental::Natural), "a''"),
+        ] {
+            let pitch = Pitch {
+                octave,
+                pitch_class,
+                cents: 0,
+            };
+
+            assert_eq!(Ok(pitch.clone()), parse_abc_notation(s));
+            assert_eq!(s, pitch.abc_notation().to_string());
+        }
+
+        assert_eq!(parse_abc_notation("C"), parse_abc_notation("=C"));
+        assert_eq!(parse_abc_notation("C"), parse_abc_notation("c,"));
+        assert_eq!(parse_abc_notation("c"), parse_abc_notation("C'"));
+
+        for s in [
+            "invalid",
+            "H",
+            "^",
+            "^^^C",
+            "C#",
+            "C4",
+            &format!("C{}", ",".repeat(260)),
+        ] {
+            assert_eq!(Err(ParsePitchError), parse_abc_notation(s));
+        }
+    }
+}
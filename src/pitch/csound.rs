@@ -0,0 +1,302 @@
+use regex::Regex;
+
+use crate::error::{FormatPitchError, ParsePitchError};
+
+use super::{
+    lenient::{self, Recase},
+    FormatOptions, ParseOptions, Pitch, PitchNotation,
+};
+
+/// Csound octave number of C0.
+const OCTAVE_OFFSET: i32 = 4;
+
+impl Pitch {
+    pub fn csound_pch_notation(&self) -> CsoundPchNotation<'_> {
+        CsoundPchNotation { pitch: self }
+    }
+
+    pub fn csound_oct_notation(&self) -> CsoundOctNotation<'_> {
+        CsoundOctNotation { pitch: self }
+    }
+
+    /// Cents above C0, including [`Pitch::cents`].
+    fn total_cents(&self) -> i64 {
+        self.semitones() as i64 * 100 + self.cents as i64
+    }
+
+    /// Splits `cents` above C0 into the nearest semitone, spelled with
+    /// sharps, and the cents left over. A quarter tone rounds down, so
+    /// the cents stay within -49..=50.
+    fn from_total_cents(cents: i64) -> Result<Pitch, ParsePitchError> {
+        let semitones = i32::try_from((cents + 49).div_euclid(100))?;
+
+        Ok(Pitch {
+            cents: (cents - semitones as i64 * 100) as _,
+            ..Pitch::from_semitones(semitones)?
+        })
+    }
+}
+
+/// Parses pch, e.g. `8.00` for C4 and `8.09` for A4. Digits after the second
+/// decimal place are a fraction of a semitone, so `8.015` is C#4 +50 cents.
+/// A single decimal digit is padded with a zero, as Csound reads it, so `8.1`
+/// is `8.10`, A#4.
+fn parse_csound_pch_notation(s: &str) -> Result<Pitch, ParsePitchError> {
+    let Some(caps) = Regex::new(r"^(?<octave>-?\d+)\.(?<digits>\d+)$")
+        .unwrap()
+        .captures(s)
+    else {
+        return Err(ParsePitchError);
+    };
+
+    let octave = caps.name("octave").unwrap().as_str().parse::<i64>()? - OCTAVE_OFFSET as i64;
+    let digits = format!("{:0<2}", caps.name("digits").unwrap().as_str());
+    let (semitone, fraction) = digits.split_at(2);
+    let semitone = semitone.parse::<i64>()?;
+    let fraction = match fraction {
+        "" => 0.0,
+        fraction => format!("0.{fraction}").parse::<f64>()?,
+    };
+
+    if semitone > 11 {
+        return Err(ParsePitchError);
+    }
+
+    Pitch::from_total_cents(
+        octave
+            .checked_mul(1200)
+            .ok_or(ParsePitchError)?
+            .saturating_add(semitone * 100 + (fraction * 100.0).round() as i64),
+    )
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CsoundPchFormat;
+
+impl PitchNotation for CsoundPchFormat {
+    fn name(&self) -> &'static str {
+        "csound-pch"
+    }
+
+    fn priority(&self) -> i32 {
+        100
+    }
+
+    fn parse(&self, s: &str, options: &ParseOptions) -> Result<Pitch, ParsePitchError> {
+        parse_csound_pch_notation(&lenient::prepare(s, options, Recase::Keep))
+    }
+
+    fn format(&self, pitch: &Pitch, _: &FormatOptions) -> Result<String, FormatPitchError> {
+        Ok(pitch.csound_pch_notation().to_string())
+    }
+}
+
+/// Parses oct, e.g. `8.0` for C4 and `8.75` for A4, where the fraction is
+/// part of an octave.
+fn parse_csound_oct_notation(s: &str) -> Result<Pitch, ParsePitchError> {
+    if !Regex::new(r"^-?\d+\.\d+$").unwrap().is_match(s) {
+        return Err(ParsePitchError);
+    }
+
+    let octaves = s.parse::<f64>()? - OCTAVE_OFFSET as f64;
+    Pitch::checked_octave(octaves.floor() as i32)?;
+
+    Pitch::from_total_cents((octaves * 1200.0).round() as i64)
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CsoundOctFormat;
+
+impl PitchNotation for CsoundOctFormat {
+    fn name(&self) -> &'static str {
+        "csound-oct"
+    }
+
+    fn priority(&self) -> i32 {
+        110
+    }
+
+    fn parse(&self, s: &str, options: &ParseOptions) -> Result<Pitch, ParsePitchError> {
+        parse_csound_oct_notation(&lenient::prepare(s, options, Recase::Keep))
+    }
+
+    fn format(&self, pitch: &Pitch, _: &FormatOptions) -> Result<String, FormatPitchError> {
+        Ok(pitch.csound_oct_notation().to_string())
+    }
+}
+
+/// Csound pch, e.g. `8.00` or `8.015` for C#4 +50 cents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsoundPchNotation<'a> {
+    pitch: &'a Pitch,
+}
+
+impl std::fmt::Display for CsoundPchNotation<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let cents = self.pitch.total_cents();
+        let octave = cents.div_euclid(1200) + OCTAVE_OFFSET as i64;
+        let semitone = cents.rem_euclid(1200) / 100;
+
+        write!(f, "{octave}.{semitone:02}")?;
+
+        match cents.rem_euclid(100) {
+            0 => Ok(()),
+            fraction => write!(f, "{}", format!("{fraction:02}").trim_end_matches('0')),
+        }
+    }
+}
+
+/// Csound oct, e.g. `8.0` or `8.75` for A4.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsoundOctNotation<'a> {
+    pitch: &'a Pitch,
+}
+
+impl std::fmt::Display for CsoundOctNotation<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let octaves = self.pitch.total_cents() as f64 / 1200.0 + OCTAVE_OFFSET as f64;
+        let s = format!("{octaves:.6}");
+
+        write!(f, "{}", s.trim_end_matches('0').trim_end_matches('.'))?;
+
+        if octaves.fract() == 0.0 {
+            write!(f, ".0")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pitch::{Accidental, Letter, PitchClass};
+
+    #[test]
+    fn test_csound_notation() {
+        for (octave, pitch_class, cents, pch, oct) in [
+            (
+                -6,
+                PitchClass::new(Letter::A, Accidental::Natural),
+                0,
+                "-2.09",
+                "-1.25",
+            ),
+            (
+                -1,
+                PitchClass::new(Letter::C, Accidental::Natural),
+                0,
+                "3.00",
+                "3.0",
+            ),
+            (
+                4,
+                PitchClass::new(Letter::C, Accidental::Natural),
+                0,
+                "8.00",
+                "8.0",
+            ),
+            (
+                4,
+                PitchClass::new(Letter::C, Accidental::Sharp),
+                0,
+                "8.01",
+                "8.083333",
+            ),
+            (
+                4,
+                PitchClass::new(Letter::F, Accidental::Sharp),
+                0,
+                "8.06",
+                "8.5",
+            ),
+            (
+                4,
+                PitchClass::new(Letter::A, Accidental::Natural),
+                0,
+                "8.09",
+                "8.75",
+            ),
+            (
+                4,
+                PitchClass::new(Letter::C, Accidental::Sharp),
+                50,
+                "8.015",
+                "8.125",
+            ),
+            (
+                3,
+                PitchClass::new(Letter::B, Accidental::Natural),
+                -25,
+                "7.1075",
+                "7.895833",
+            ),
+            (
+                9,
+                PitchClass::new(Letter::D, Accidental::Natural),
+                5,
+                "13.0205",
+                "13.170833",
+            ),
+        ] {
+            let pitch = Pitch {
+                octave,
+                pitch_class,
+                cents,
+            };
+
+            assert_eq!(Ok(pitch.clone()), parse_csound_pch_notation(pch));
+            assert_eq!(Ok(pitch.clone()), parse_csound_oct_notation(oct));
+            assert_eq!(pch, pitch.csound_pch_notation().to_string());
+            assert_eq!(oct, pitch.csound_oct_notation().to_string());
+        }
+    }
+
+    #[test]
+    fn test_csound_notation_respelling() {
+        let d_flat_4 = Pitch {
+            octave: 4,
+            pitch_class: PitchClass::new(Letter::D, Accidental::Flat),
+            cents: 0,
+        };
+        assert_eq!("8.01", d_flat_4.csound_pch_notation().to_string());
+
+        let b_sharp_3 = Pitch {
+            octave: 3,
+            pitch_class: PitchClass::new(Letter::B, Accidental::Sharp),
+            cents: 0,
+        };
+        assert_eq!("8.00", b_sharp_3.csound_pch_notation().to_string());
+        assert_eq!("8.0", b_sharp_3.csound_oct_notation().to_string());
+
+        assert_eq!(
+            Ok(Pitch {
+                octave: 3,
+                pitch_class: PitchClass::new(Letter::B, Accidental::Natural),
+                cents: 50,
+            }),
+            parse_csound_pch_notation("7.115"),
+        );
+
+        assert_eq!(
+            parse_csound_pch_notation("8.10"),
+            parse_csound_pch_notation("8.1")
+        );
+        assert_eq!(
+            parse_csound_pch_notation("8.00"),
+            parse_csound_pch_notation("8.0")
+        );
+    }
+
+    #[test]
+    fn test_parse_csound_notation_invalid() {
+        for s in [
+            "invalid", "8", "8.", "8.12", "8.2", "-252.00", "260.00", ".00", "8.00c",
+        ] {
+            assert_eq!(Err(ParsePitchError), parse_csound_pch_notation(s));
+        }
+        for s in ["invalid", "8", "-251.5", "260.0", ".5", "8.", "--1.0"] {
+            assert_eq!(Err(ParsePitchError), parse_csound_oct_notation(s));
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use regex::Regex;
+
+use crate::error::{FormatPitchError, ParsePitchClassError, ParsePitchError};
+
+use super::{
+    fmt_cents,
+    lenient::{self, Recase},
+    parse_cents, Accidental, FormatOptions, Letter, MiddleC, ParseOptions, Pitch, PitchClass,
+    PitchClassFormat, PitchNotation, CENTS_PATTERN,
+};
+
+const LETTERS: [Letter; 7] = [
+    Letter::C,
+    Letter::D,
+    Letter::E,
+    Letter::F,
+    Letter::G,
+    Letter::A,
+    Letter::B,
+];
+
+const ACCIDENTALS: [Accidental; 5] = [
+    Accidental::DoubleFlat,
+    Accidental::Flat,
+    Accidental::Natural,
+    Accidental::Sharp,
+    Accidental::DoubleSharp,
+];
+
+impl Pitch {
+    pub fn german_pitch_notation(&self) -> GermanPitchNotation<'_> {
+        GermanPitchNotation {
+            pitch: self,
+            middle_c: MiddleC::default(),
+        }
+    }
+}
+
+/// German name of a pitch class: `H` is B natural, `B` is B flat and
+/// accidentals are spelled as `-is`/`-es` suffixes (`Cis`, `Es`, `As`).
+pub(super) fn german_pitch_class_name(pitch_class: &PitchClass) -> String {
+    let letter = match pitch_class.letter {
+        Letter::B => "H",
+        letter => letter.as_str(),
+    };
+
+    match (pitch_class.letter, pitch_class.accidental) {
+        (_, Accidental::Natural) => letter.to_string(),
+        (_, Accidental::Sharp) => format!("{letter}is"),
+        (_, Accidental::DoubleSharp) => format!("{letter}isis"),
+        (Letter::B, Accidental::Flat) => "B".to_string(),
+        (Letter::E | Letter::A, Accidental::Flat) => format!("{letter}s"),
+        (Letter::E | Letter::A, Accidental::DoubleFlat) => format!("{letter}ses"),
+        (_, Accidental::Flat) => format!("{letter}es"),
+        (_, Accidental::DoubleFlat) => format!("{letter}eses"),
+    }
+}
+
+pub(super) fn parse_german_pitch_class(s: &str) -> Result<PitchClass, ParsePitchClassError> {
+    LETTERS
+        .into_iter()
+        .flat_map(|letter| {
+            ACCIDENTALS
+                .into_iter()
+                .map(move |accidental| PitchClass::new(letter, accidental))
+        })
+        .find(|pitch_class| german_pitch_class_name(pitch_class) == s)
+        .ok_or(ParsePitchClassError)
+}
+
+fn parse_german_pitch_notation(s: &str, options: &ParseOptions) -> Result<Pitch, ParsePitchError> {
+    let Some(caps) = Regex::new(&format!(
+        r"^(?<pitch_class>[A-H][a-z]*)(?<octave>0|(-?[1-9]\d*))(?<cents>{CENTS_PATTERN})?$"
+    ))
+    .unwrap()
+    .captures(s) else {
+        return Err(ParsePitchError);
+    };
+
+    let octave =
+        caps.name("octave").unwrap().as_str().parse::<i32>()? + options.middle_c.octave_offset();
+    let pitch_class = parse_german_pitch_class(caps.name("pitch_class").unwrap().as_str())?;
+
+    let octave = Pitch::checked_octave(octave)?;
+
+    Ok(Pitch {
+        octave,
+        pitch_class,
+        cents: parse_cents(caps.name("cents").map(|m| m.as_str()))?,
+    })
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GermanFormat;
+
+impl PitchNotation for GermanFormat {
+    fn name(&self) -> &'static str {
+        "german"
+    }
+
+    fn priority(&self) -> i32 {
+        50
+    }
+
+    fn parse(&self, s: &str, options: &ParseOptions) -> Result<Pitch, ParsePitchError> {
+        parse_german_pitch_notation(&lenient::prepare(s, options, Recase::Capitalize), options)
+    }
+
+    fn format(&self, pitch: &Pitch, options: &FormatOptions) -> Result<String, FormatPitchError> {
+        Ok(pitch
+            .german_pitch_notation()
+            .middle_c(options.middle_c)
+            .to_string())
+    }
+
+    fn parse_pitch_class(
+        &self,
+        s: &str,
+        options: &ParseOptions,
+    ) -> Result<PitchClass, ParsePitchClassError> {
+        PitchClassFormat::German.parse_normalized(s, options)
+    }
+
+    fn format_pitch_class(
+        &self,
+        pitch_class: &PitchClass,
+        options: &FormatOptions,
+    ) -> Result<String, FormatPitchError> {
+        Ok(PitchClassFormat::German.format(pitch_class, options))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GermanPitchNotation<'a> {
+    pitch: &'a Pitch,
+    middle_c: MiddleC,
+}
+
+impl GermanPitchNotation<'_> {
+    pub fn middle_c(mut self, middle_c: MiddleC) -> Self {
+        self.middle_c = middle_c;
+        self
+    }
+}
+
+impl std::fmt::Display for GermanPitchNotation<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}",
+            german_pitch_class_name(&self.pitch.pitch_class),
+            self.pitch.octave as i32 - self.middle_c.octave_offset(),
+        )?;
+
+        fmt_cents(self.pitch.cents, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_german_pitch_class_name() {
+        for (letter, names) in [
+            (Letter::C, ["Ceses", "Ces", "C", "Cis", "Cisis"]),
+            (Letter::D, ["Deses", "Des", "D", "Dis", "Disis"]),
+            (Letter::E, ["Eses", "Es", "E", "Eis", "Eisis"]),
+            (Letter::F, ["Feses", "Fes", "F", "Fis", "Fisis"]),
+            (Letter::G, ["Geses", "Ges", "G", "Gis", "Gisis"]),
+            (Letter::A, ["Ases", "As", "A", "Ais", "Aisis"]),
+            (Letter::B, ["Heses", "B", "H", "His", "Hisis"]),
+        ] {
+            for (accidental, name) in ACCIDENTALS.into_iter().zip(names) {
+                let pitch_class = PitchClass::new(letter, accidental);
+
+                assert_eq!(name, german_pitch_class_name(&pitch_class));
+                assert_eq!(Ok(pitch_class), parse_german_pitch_class(name));
+            }
+        }
+
+        for s in ["Hes", "Ees", "Aes", "Bes", "cis", "CIS", "Cs", "I"] {
+            assert_eq!(Err(ParsePitchClassError), parse_german_pitch_class(s));
+        }
+    }
+
+    #[test]
+    fn test_german_pitch_notation() {
+        for (pitch, s) in [
+            (
+                Pitch {
+                    octave: 3,
+                    pitch_class: PitchClass::new(Letter::B, Accidental::Flat),
+                    cents: 0,
+                },
+                "B3",
+            ),
+            (
+                Pitch {
+                    octave: 3,
+                    pitch_class: PitchClass::new(Letter::B, Accidental::Natural),
+                    cents: 0,
+                },
+                "H3",
+            ),
+            (
+                Pitch {
+                    octave: 4,
+                    pitch_class: PitchClass::new(Letter::C, Accidental::Sharp),
+                    cents: 0,
+                },
+                "Cis4",
+            ),
+            (
+                Pitch {
+                    octave: -1,
+                    pitch_class: PitchClass::new(Letter::E, Accidental::Flat),
+                    cents: 12,
+                },
+                "Es-1+12c",
+            ),
+        ] {
+            assert_eq!(
+                Ok(pitch.clone()),
+                parse_german_pitch_notation(s, &ParseOptions::default())
+            );
+            assert_eq!(s, pitch.german_pitch_notation().to_string());
+        }
+
+        let c4 = Pitch {
+            octave: 4,
+            pitch_class: PitchClass::new(Letter::C, Accidental::Natural),
+            cents: 0,
+        };
+        let yamaha = ParseOptions {
+            middle_c: MiddleC::C3,
+            ..Default::default()
+        };
+        assert_eq!(Ok(c4.clone()), parse_german_pitch_notation("C3", &yamaha));
+        assert_eq!(
+            "C3",
+            c4.german_pitch_notation().middle_c(MiddleC::C3).to_string(),
+        );
+
+        for s in ["invalid", "Cis", "Hes4", "H-256", "Cis256"] {
+            assert_eq!(
+                Err(ParsePitchError),
+                parse_german_pitch_notation(s, &ParseOptions::default())
+            );
+        }
+    }
+}
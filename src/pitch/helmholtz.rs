@@ -0,0 +1,361 @@
+use regex::Regex;
+
+use crate::error::{FormatPitchError, ParsePitchError};
+
+use super::{
+    fmt_cents,
+    lenient::{self, Recase},
+    parse_cents, AccidentalStyle, FormatOptions, ParseOptions, Pitch, PitchClass, PitchNotation,
+    ACCIDENTAL_PATTERN, CENTS_PATTERN, SMALL_OCTAVE,
+};
+
+const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+const SUBSCRIPT_DIGITS: [char; 10] = ['₀', '₁', '₂', '₃', '₄', '₅', '₆', '₇', '₈', '₉'];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HelmholtzStyle {
+    /// `c'`, `c''`, `C,`, `C,,`
+    #[default]
+    Marks,
+    /// `c¹`, `c²`, `C₁`, `C₂`
+    Indices,
+}
+
+impl Pitch {
+    pub fn helmholtz_pitch_notation(&self) -> HelmholtzPitchNotation<'_> {
+        HelmholtzPitchNotation {
+            pitch: self,
+            accidental_style: AccidentalStyle::default(),
+            style: HelmholtzStyle::default(),
+        }
+    }
+}
+
+fn parse_helmholtz_pitch_notation(s: &str) -> Result<Pitch, ParsePitchError> {
+    let Some(caps) = Regex::new(&format!(
+        r"^(?<letter>[A-Ga-g])(?<accidental>{ACCIDENTAL_PATTERN})?((?<primes>['′″]+)|(?<superscript>[¹²³⁴⁵⁶⁷⁸⁹][⁰¹²³⁴⁵⁶⁷⁸⁹]*)|(?<commas>,+)|(?<subscript>[₁₂₃₄₅₆₇₈₉][₀₁₂₃₄₅₆₇₈₉]*))?(?<cents>{CENTS_PATTERN})?$"
+    ))
+    .unwrap()
+    .captures(s) else {
+        return Err(ParsePitchError);
+    };
+
+    let letter = caps.name("letter").unwrap().as_str();
+    let is_lowercase = letter.chars().all(|c| c.is_ascii_lowercase());
+
+    let pitch_class: PitchClass = format!(
+        "{}{}",
+        letter.to_ascii_uppercase(),
+        caps.name("accidental").map_or("", |m| m.as_str()),
+    )
+    .parse()?;
+
+    let octave = match (
+        is_lowercase,
+        caps.name("primes"),
+        caps.name("superscript"),
+        caps.name("commas"),
+        caps.name("subscript"),
+    ) {
+        (true, Some(primes), None, None, None) => {
+            let count: i32 = primes
+                .as_str()
+                .chars()
+                .map(|c| if c == '″' { 2 } else { 1 })
+                .sum();
+
+            SMALL_OCTAVE + count
+        }
+        (true, None, Some(superscript), None, None) => {
+            SMALL_OCTAVE + parse_digits(superscript.as_str(), &SUPERSCRIPT_DIGITS)?
+        }
+        (true, None, None, None, None) => SMALL_OCTAVE,
+        (false, None, None, None, None) => SMALL_OCTAVE - 1,
+        (false, None, None, Some(commas), None) => SMALL_OCTAVE - 1 - commas.as_str().len() as i32,
+        (false, None, None, None, Some(subscript)) => {
+            SMALL_OCTAVE - 1 - parse_digits(subscript.as_str(), &SUBSCRIPT_DIGITS)?
+        }
+        _ => return Err(ParsePitchError),
+    };
+
+    let octave = Pitch::checked_octave(octave)?;
+
+    Ok(Pitch {
+        octave,
+        pitch_class,
+        cents: parse_cents(caps.name("cents").map(|m| m.as_str()))?,
+    })
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HelmholtzFormat;
+
+impl PitchNotation for HelmholtzFormat {
+    fn name(&self) -> &'static str {
+        "helmholtz"
+    }
+
+    fn priority(&self) -> i32 {
+        20
+    }
+
+    fn parse(&self, s: &str, options: &ParseOptions) -> Result<Pitch, ParsePitchError> {
+        parse_helmholtz_pitch_notation(&lenient::prepare(s, options, Recase::Keep))
+    }
+
+    fn format(&self, pitch: &Pitch, options: &FormatOptions) -> Result<String, FormatPitchError> {
+        Ok(pitch
+            .helmholtz_pitch_notation()
+            .accidental_style(options.accidental_style)
+            .style(options.helmholtz_style)
+            .to_string())
+    }
+}
+
+fn parse_digits(s: &str, digits: &[char; 10]) -> Result<i32, ParsePitchError> {
+    s.chars().try_fold(0i32, |n, c| {
+        let digit = digits.iter().position(|&d| d == c).ok_or(ParsePitchError)?;
+
+        n.checked_mul(10)
+            .and_then(|n| n.checked_add(digit as i32))
+            .ok_or(ParsePitchError)
+    })
+}
+
+fn fmt_digits(n: i32, digits: &[char; 10], f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    for c in n.to_string().chars() {
+        write!(f, "{}", digits[c.to_digit(10).unwrap() as usize])?;
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HelmholtzPitchNotation<'a> {
+    pitch: &'a Pitch,
+    accidental_style: AccidentalStyle,
+    style: HelmholtzStyle,
+}
+
+impl HelmholtzPitchNotation<'_> {
+    pub fn accidental_style(mut self, accidental_style: AccidentalStyle) -> Self {
+        self.accidental_style = accidental_style;
+        self
+    }
+
+    pub fn style(mut self, style: HelmholtzStyle) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl std::fmt::Display for HelmholtzPitchNotation<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let pitch_class = &self.pitch.pitch_class;
+        let octave = self.pitch.octave as i32;
+        let accidental = pitch_class.accidental.symbol(self.accidental_style);
+
+        if octave >= SMALL_OCTAVE {
+            let letter = pitch_class.letter.as_str().to_ascii_lowercase();
+            let count = octave - SMALL_OCTAVE;
+
+            write!(f, "{letter}{accidental}")?;
+
+            match (self.style, count) {
+                (_, 0) => {}
+                (HelmholtzStyle::Marks, n) => {
+                    for _ in 0..n {
+                        write!(f, "'")?;
+                    }
+                }
+                (HelmholtzStyle::Indices, n) => fmt_digits(n, &SUPERSCRIPT_DIGITS, f)?,
+            }
+        } else {
+            let count = SMALL_OCTAVE - 1 - octave;
+
+            write!(f, "{}{accidental}", pitch_class.letter)?;
+
+            match (self.style, count) {
+                (_, 0) => {}
+                (HelmholtzStyle::Marks, n) => {
+                    for _ in 0..n {
+                        write!(f, ",")?;
+                    }
+                }
+                (HelmholtzStyle::Indices, n) => fmt_digits(n, &SUBSCRIPT_DIGITS, f)?,
+            }
+        }
+
+        fmt_cents(self.pitch.cents, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pitch::{Accidental, Letter};
+
+    struct HelmholtzCase {
+        pitch: Pitch,
+        marks: &'static str,
+        indices: &'static str,
+    }
+
+    const HELMHOLTZ_CASES: [HelmholtzCase; 9] = [
+        HelmholtzCase {
+            pitch: Pitch {
+                octave: -1,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Natural),
+                cents: 0,
+            },
+            marks: "C,,,",
+            indices: "C₃",
+        },
+        HelmholtzCase {
+            pitch: Pitch {
+                octave: 0,
+                pitch_class: PitchClass::new(Letter::A, Accidental::Natural),
+                cents: 0,
+            },
+            marks: "A,,",
+            indices: "A₂",
+        },
+        HelmholtzCase {
+            pitch: Pitch {
+                octave: 1,
+                pitch_class: PitchClass::new(Letter::E, Accidental::Flat),
+                cents: 0,
+            },
+            marks: "Eb,",
+            indices: "Eb₁",
+        },
+        HelmholtzCase {
+            pitch: Pitch {
+                octave: 2,
+                pitch_class: PitchClass::new(Letter::B, Accidental::Natural),
+                cents: 0,
+            },
+            marks: "B",
+            indices: "B",
+        },
+        HelmholtzCase {
+            pitch: Pitch {
+                octave: 3,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Natural),
+                cents: 0,
+            },
+            marks: "c",
+            indices: "c",
+        },
+        HelmholtzCase {
+            pitch: Pitch {
+                octave: 3,
+                pitch_class: PitchClass::new(Letter::B, Accidental::Flat),
+                cents: 0,
+            },
+            marks: "bb",
+            indices: "bb",
+        },
+        HelmholtzCase {
+            pitch: Pitch {
+                octave: 4,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Natural),
+                cents: 0,
+            },
+            marks: "c'",
+            indices: "c¹",
+        },
+        HelmholtzCase {
+            pitch: Pitch {
+                octave: 5,
+                pitch_class: PitchClass::new(Letter::F, Accidental::Sharp),
+                cents: 0,
+            },
+            marks: "f#''",
+            indices: "f#²",
+        },
+        HelmholtzCase {
+            pitch: Pitch {
+                octave: 15,
+                pitch_class: PitchClass::new(Letter::G, Accidental::Natural),
+                cents: 0,
+            },
+            marks: "g''''''''''''",
+            indices: "g¹²",
+        },
+    ];
+
+    #[test]
+    fn test_parse_helmholtz_pitch_notation() {
+        for case in HELMHOLTZ_CASES {
+            assert_eq!(
+                Ok(case.pitch.clone()),
+                parse_helmholtz_pitch_notation(case.marks),
+            );
+            assert_eq!(Ok(case.pitch), parse_helmholtz_pitch_notation(case.indices));
+        }
+
+        let c5 = Pitch {
+            octave: 5,
+            pitch_class: PitchClass::new(Letter::C, Accidental::Natural),
+            cents: 0,
+        };
+        assert_eq!(Ok(c5.clone()), parse_helmholtz_pitch_notation("c″"));
+        assert_eq!(Ok(c5.clone()), parse_helmholtz_pitch_notation("c′′"));
+        assert_eq!(Ok(c5), parse_helmholtz_pitch_notation("c'′"));
+        assert_eq!(
+            Ok(Pitch {
+                octave: 4,
+                pitch_class: PitchClass::new(Letter::A, Accidental::Natural),
+                cents: -30,
+            }),
+            parse_helmholtz_pitch_notation("a'-30c"),
+        );
+
+        for s in [
+            "invalid",
+            "C'",
+            "c,",
+            "C¹",
+            "c₁",
+            "c'¹",
+            "C,₁",
+            &format!("C{}", ",".repeat(258)),
+            "h",
+            "c⁰",
+            "C₀",
+        ] {
+            assert_eq!(Err(ParsePitchError), parse_helmholtz_pitch_notation(s));
+        }
+    }
+
+    #[test]
+    fn test_helmholtz_pitch_notation_to_string() {
+        for case in HELMHOLTZ_CASES {
+            assert_eq!(
+                case.marks,
+                case.pitch.helmholtz_pitch_notation().to_string()
+            );
+            assert_eq!(
+                case.indices,
+                case.pitch
+                    .helmholtz_pitch_notation()
+                    .style(HelmholtzStyle::Indices)
+                    .to_string(),
+            );
+        }
+
+        let f_sharp_4 = Pitch {
+            octave: 4,
+            pitch_class: PitchClass::new(Letter::F, Accidental::Sharp),
+            cents: 0,
+        };
+        assert_eq!(
+            "f♯'",
+            f_sharp_4
+                .helmholtz_pitch_notation()
+                .accidental_style(AccidentalStyle::Unicode)
+                .to_string(),
+        );
+    }
+}
@@ -0,0 +1,540 @@
+use regex::Regex;
+
+use crate::error::{FormatPitchError, ParsePitchClassError, ParsePitchError};
+
+use super::{
+    fmt_cents,
+    lenient::{self, Recase},
+    parse_cents, Accidental, AccidentalStyle, FormatOptions, Letter, MiddleC, ParseOptions, Pitch,
+    PitchClass, PitchClassFormat, PitchNotation, ACCIDENTAL_PATTERN, CENTS_PATTERN, SMALL_OCTAVE,
+};
+
+const KANJI_DIGITS: [char; 10] = ['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+
+impl Pitch {
+    pub fn japanese_pitch_notation(&self) -> JapanesePitchNotation<'_> {
+        JapanesePitchNotation { pitch: self }
+    }
+
+    pub fn katakana_solfege_notation(&self) -> KatakanaSolfegeNotation<'_> {
+        KatakanaSolfegeNotation {
+            pitch: self,
+            accidental_style: AccidentalStyle::default(),
+            middle_c: MiddleC::default(),
+        }
+    }
+}
+
+fn kana(letter: Letter) -> &'static str {
+    match letter {
+        Letter::C => "ハ",
+        Letter::D => "ニ",
+        Letter::E => "ホ",
+        Letter::F => "ヘ",
+        Letter::G => "ト",
+        Letter::A => "イ",
+        Letter::B => "ロ",
+    }
+}
+
+pub(super) fn katakana_solfege_syllable(letter: Letter) -> &'static str {
+    match letter {
+        Letter::C => "ド",
+        Letter::D => "レ",
+        Letter::E => "ミ",
+        Letter::F => "ファ",
+        Letter::G => "ソ",
+        Letter::A => "ラ",
+        Letter::B => "シ",
+    }
+}
+
+/// Japanese name of a pitch class, e.g. `嬰ヘ` for F# and `変ロ` for Bb.
+pub(super) fn japanese_pitch_class_name(pitch_class: &PitchClass) -> String {
+    let accidental = match pitch_class.accidental {
+        Accidental::DoubleFlat => "重変",
+        Accidental::Flat => "変",
+        Accidental::Natural => "",
+        Accidental::Sharp => "嬰",
+        Accidental::DoubleSharp => "重嬰",
+    };
+
+    format!("{accidental}{}", kana(pitch_class.letter))
+}
+
+pub(super) fn parse_japanese_pitch_class(s: &str) -> Result<PitchClass, ParsePitchClassError> {
+    let (accidental, kana) = [
+        ("重変", Accidental::DoubleFlat),
+        ("変", Accidental::Flat),
+        ("重嬰", Accidental::DoubleSharp),
+        ("嬰", Accidental::Sharp),
+    ]
+    .into_iter()
+    .find_map(|(prefix, accidental)| Some((accidental, s.strip_prefix(prefix)?)))
+    .unwrap_or((Accidental::Natural, s));
+
+    let letter = match kana {
+        "ハ" => Letter::C,
+        "ニ" => Letter::D,
+        "ホ" => Letter::E,
+        "ヘ" => Letter::F,
+        "ト" => Letter::G,
+        "イ" => Letter::A,
+        "ロ" => Letter::B,
+        _ => return Err(ParsePitchClassError),
+    };
+
+    Ok(PitchClass::new(letter, accidental))
+}
+
+pub(super) fn parse_katakana_solfege_syllable(s: &str) -> Result<Letter, ParsePitchClassError> {
+    let letter = match s {
+        "ド" => Letter::C,
+        "レ" => Letter::D,
+        "ミ" => Letter::E,
+        "ファ" => Letter::F,
+        "ソ" => Letter::G,
+        "ラ" => Letter::A,
+        "シ" => Letter::B,
+        _ => return Err(ParsePitchClassError),
+    };
+
+    Ok(letter)
+}
+
+/// Kanji numeral for `1..1000`, e.g. `二十一` for 21.
+fn kanji_numeral(n: u32) -> String {
+    let mut s = String::new();
+
+    for (unit, place) in [(100, "百"), (10, "十")] {
+        match n / unit % 10 {
+            0 => {}
+            1 => s.push_str(place),
+            d => {
+                s.push(KANJI_DIGITS[d as usize]);
+                s.push_str(place);
+            }
+        }
+    }
+
+    if !n.is_multiple_of(10) {
+        s.push(KANJI_DIGITS[(n % 10) as usize]);
+    }
+
+    s
+}
+
+fn parse_kanji_numeral(s: &str) -> Result<u32, ParsePitchError> {
+    let mut n = 0;
+    let mut digit = None;
+
+    for c in s.chars() {
+        match c {
+            '百' => n += digit.take().unwrap_or(1) * 100,
+            '十' => n += digit.take().unwrap_or(1) * 10,
+            c => match KANJI_DIGITS.iter().position(|&d| d == c) {
+                Some(d) if d > 0 && digit.is_none() => digit = Some(d as u32),
+                _ => return Err(ParsePitchError),
+            },
+        }
+    }
+
+    n += digit.unwrap_or(0);
+
+    // Rejects non-canonical spellings such as `一十` or `十百`.
+    if n == 0 || kanji_numeral(n) != s {
+        return Err(ParsePitchError);
+    }
+
+    Ok(n)
+}
+
+fn parse_japanese_pitch_notation(s: &str) -> Result<Pitch, ParsePitchError> {
+    let Some(caps) = Regex::new(&format!(
+        r"^(?<octave>小字|大字|(?<below>下)?(?<count>[一二三四五六七八九十百]+)点)(?<pitch_class>(重変|変|重嬰|嬰)?[ハニホヘトイロ])(?<cents>{CENTS_PATTERN})?$"
+    ))
+    .unwrap()
+    .captures(s) else {
+        return Err(ParsePitchError);
+    };
+
+    let octave = match caps.name("octave").unwrap().as_str() {
+        "小字" => SMALL_OCTAVE,
+        "大字" => SMALL_OCTAVE - 1,
+        _ => {
+            let count = parse_kanji_numeral(caps.name("count").unwrap().as_str())? as i32;
+
+            match caps.name("below") {
+                Some(_) => SMALL_OCTAVE - 1 - count,
+                None => SMALL_OCTAVE + count,
+            }
+        }
+    };
+
+    let octave = Pitch::checked_octave(octave)?;
+
+    Ok(Pitch {
+        octave,
+        pitch_class: parse_japanese_pitch_class(caps.name("pitch_class").unwrap().as_str())?,
+        cents: parse_cents(caps.name("cents").map(|m| m.as_str()))?,
+    })
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JapaneseFormat;
+
+impl PitchNotation for JapaneseFormat {
+    fn name(&self) -> &'static str {
+        "japanese"
+    }
+
+    fn priority(&self) -> i32 {
+        60
+    }
+
+    fn parse(&self, s: &str, options: &ParseOptions) -> Result<Pitch, ParsePitchError> {
+        parse_japanese_pitch_notation(&lenient::prepare(s, options, Recase::Keep))
+    }
+
+    fn format(&self, pitch: &Pitch, _: &FormatOptions) -> Result<String, FormatPitchError> {
+        Ok(pitch.japanese_pitch_notation().to_string())
+    }
+
+    fn parse_pitch_class(
+        &self,
+        s: &str,
+        options: &ParseOptions,
+    ) -> Result<PitchClass, ParsePitchClassError> {
+        PitchClassFormat::Japanese.parse_normalized(s, options)
+    }
+
+    fn format_pitch_class(
+        &self,
+        pitch_class: &PitchClass,
+        options: &FormatOptions,
+    ) -> Result<String, FormatPitchError> {
+        Ok(PitchClassFormat::Japanese.format(pitch_class, options))
+    }
+}
+
+fn parse_katakana_solfege_notation(
+    s: &str,
+    options: &ParseOptions,
+) -> Result<Pitch, ParsePitchError> {
+    let Some(caps) = Regex::new(&format!(
+        r"^(?<syllable>ド|レ|ミ|ファ|ソ|ラ|シ)(?<accidental>{ACCIDENTAL_PATTERN})?(?<octave>0|(-?[1-9]\d*))(?<cents>{CENTS_PATTERN})?$"
+    ))
+    .unwrap()
+    .captures(s) else {
+        return Err(ParsePitchError);
+    };
+
+    let letter = parse_katakana_solfege_syllable(caps.name("syllable").unwrap().as_str())?;
+    let pitch_class: PitchClass = format!(
+        "{letter}{}",
+        caps.name("accidental").map_or("", |m| m.as_str()),
+    )
+    .parse()?;
+    let octave =
+        caps.name("octave").unwrap().as_str().parse::<i32>()? + options.middle_c.octave_offset();
+
+    let octave = Pitch::checked_octave(octave)?;
+
+    Ok(Pitch {
+        octave,
+        pitch_class,
+        cents: parse_cents(caps.name("cents").map(|m| m.as_str()))?,
+    })
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KatakanaSolfegeFormat;
+
+impl PitchNotation for KatakanaSolfegeFormat {
+    fn name(&self) -> &'static str {
+        "katakana-solfege"
+    }
+
+    fn priority(&self) -> i32 {
+        70
+    }
+
+    fn parse(&self, s: &str, options: &ParseOptions) -> Result<Pitch, ParsePitchError> {
+        parse_katakana_solfege_notation(&lenient::prepare(s, options, Recase::Keep), options)
+    }
+
+    fn format(&self, pitch: &Pitch, options: &FormatOptions) -> Result<String, FormatPitchError> {
+        Ok(pitch
+            .katakana_solfege_notation()
+            .accidental_style(options.accidental_style)
+            .middle_c(options.middle_c)
+            .to_string())
+    }
+
+    fn parse_pitch_class(
+        &self,
+        s: &str,
+        options: &ParseOptions,
+    ) -> Result<PitchClass, ParsePitchClassError> {
+        PitchClassFormat::KatakanaSolfege.parse_normalized(s, options)
+    }
+
+    fn format_pitch_class(
+        &self,
+        pitch_class: &PitchClass,
+        options: &FormatOptions,
+    ) -> Result<String, FormatPitchError> {
+        Ok(PitchClassFormat::KatakanaSolfege.format(pitch_class, options))
+    }
+}
+
+/// Traditional Japanese pitch names: `一点ハ` is C4, `小字ハ` is C3, `大字ハ`
+/// is C2 and `下一点ハ` is C1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JapanesePitchNotation<'a> {
+    pitch: &'a Pitch,
+}
+
+impl std::fmt::Display for JapanesePitchNotation<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.pitch.octave as i32 {
+            SMALL_OCTAVE => write!(f, "小字")?,
+            o if o == SMALL_OCTAVE - 1 => write!(f, "大字")?,
+            o if o > SMALL_OCTAVE => write!(f, "{}点", kanji_numeral((o - SMALL_OCTAVE) as _))?,
+            o => write!(f, "下{}点", kanji_numeral((SMALL_OCTAVE - 1 - o) as _))?,
+        }
+
+        write!(f, "{}", japanese_pitch_class_name(&self.pitch.pitch_class))?;
+
+        fmt_cents(self.pitch.cents, f)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KatakanaSolfegeNotation<'a> {
+    pitch: &'a Pitch,
+    accidental_style: AccidentalStyle,
+    middle_c: MiddleC,
+}
+
+impl KatakanaSolfegeNotation<'_> {
+    pub fn accidental_style(mut self, accidental_style: AccidentalStyle) -> Self {
+        self.accidental_style = accidental_style;
+        self
+    }
+
+    pub fn middle_c(mut self, middle_c: MiddleC) -> Self {
+        self.middle_c = middle_c;
+        self
+    }
+}
+
+impl std::fmt::Display for KatakanaSolfegeNotation<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let pitch_class = &self.pitch.pitch_class;
+
+        write!(
+            f,
+            "{}{}{}",
+            katakana_solfege_syllable(pitch_class.letter),
+            pitch_class.accidental.symbol(self.accidental_style),
+            self.pitch.octave as i32 - self.middle_c.octave_offset(),
+        )?;
+
+        fmt_cents(self.pitch.cents, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kanji_numeral() {
+        for (n, s) in [
+            (1, "一"),
+            (9, "九"),
+            (10, "十"),
+            (11, "十一"),
+            (20, "二十"),
+            (100, "百"),
+            (105, "百五"),
+            (252, "二百五十二"),
+        ] {
+            assert_eq!(s, kanji_numeral(n));
+            assert_eq!(Ok(n), parse_kanji_numeral(s));
+        }
+
+        for s in ["", "〇", "一十", "十百", "一一", "二二十", "一百"] {
+            assert_eq!(Err(ParsePitchError), parse_kanji_numeral(s));
+        }
+    }
+
+    #[test]
+    fn test_japanese_pitch_notation() {
+        for (octave, pitch_class, s) in [
+            (
+                -1,
+                PitchClass::new(Letter::C, Accidental::Natural),
+                "下三点ハ",
+            ),
+            (
+                0,
+                PitchClass::new(Letter::A, Accidental::Natural),
+                "下二点イ",
+            ),
+            (
+                1,
+                PitchClass::new(Letter::E, Accidental::Flat),
+                "下一点変ホ",
+            ),
+            (2, PitchClass::new(Letter::B, Accidental::Natural), "大字ロ"),
+            (3, PitchClass::new(Letter::G, Accidental::Sharp), "小字嬰ト"),
+            (4, PitchClass::new(Letter::C, Accidental::Natural), "一点ハ"),
+            (4, PitchClass::new(Letter::F, Accidental::Sharp), "一点嬰ヘ"),
+            (4, PitchClass::new(Letter::B, Accidental::Flat), "一点変ロ"),
+            (
+                5,
+                PitchClass::new(Letter::D, Accidental::DoubleSharp),
+                "二点重嬰ニ",
+            ),
+            (
+                6,
+                PitchClass::new(Letter::A, Accidental::DoubleFlat),
+                "三点重変イ",
+            ),
+            (
+                14,
+                PitchClass::new(Letter::C, Accidental::Natural),
+                "十一点ハ",
+            ),
+        ] {
+            let pitch = Pitch {
+                octave,
+                pitch_class,
+                cents: 0,
+            };
+
+            assert_eq!(Ok(pitch.clone()), parse_japanese_pitch_notation(s));
+            assert_eq!(s, pitch.japanese_pitch_notation().to_string());
+        }
+
+        assert_eq!(
+            Ok(Pitch {
+                octave: 4,
+                pitch_class: PitchClass::new(Letter::A, Accidental::Natural),
+                cents: 15,
+            }),
+            parse_japanese_pitch_notation("一点イ+15c"),
+        );
+
+        for s in [
+            "invalid",
+            "ハ",
+            "一点",
+            "一点ド",
+            "嬰一点ヘ",
+            "下二百五十八点ハ",
+            "〇点ハ",
+        ] {
+            assert_eq!(Err(ParsePitchError), parse_japanese_pitch_notation(s));
+        }
+    }
+
+    #[test]
+    fn test_japanese_pitch_class_name() {
+        for (pitch_class, s) in [
+            (PitchClass::new(Letter::C, Accidental::Natural), "ハ"),
+            (PitchClass::new(Letter::F, Accidental::Sharp), "嬰ヘ"),
+            (PitchClass::new(Letter::B, Accidental::Flat), "変ロ"),
+            (
+                PitchClass::new(Letter::G, Accidental::DoubleSharp),
+                "重嬰ト",
+            ),
+            (PitchClass::new(Letter::E, Accidental::DoubleFlat), "重変ホ"),
+        ] {
+            assert_eq!(s, japanese_pitch_class_name(&pitch_class));
+            assert_eq!(Ok(pitch_class), parse_japanese_pitch_class(s));
+        }
+
+        assert_eq!(Err(ParsePitchClassError), parse_japanese_pitch_class("嬰"));
+        assert_eq!(
+            Err(ParsePitchClassError),
+            parse_japanese_pitch_class("ヘ嬰")
+        );
+    }
+
+    #[test]
+    fn test_katakana_solfege_notation() {
+        for (octave, pitch_class, s) in [
+            (-1, PitchClass::new(Letter::C, Accidental::Natural), "ド-1"),
+            (3, PitchClass::new(Letter::F, Accidental::Sharp), "ファ#3"),
+            (4, PitchClass::new(Letter::C, Accidental::Natural), "ド4"),
+            (4, PitchClass::new(Letter::E, Accidental::Natural), "ミ4"),
+            (4, PitchClass::new(Letter::G, Accidental::Natural), "ソ4"),
+            (4, PitchClass::new(Letter::A, Accidental::Natural), "ラ4"),
+            (4, PitchClass::new(Letter::B, Accidental::Flat), "シb4"),
+            (
+                5,
+                PitchClass::new(Letter::D, Accidental::DoubleSharp),
+                "レ##5",
+            ),
+        ] {
+            let pitch = Pitch {
+                octave,
+                pitch_class,
+                cents: 0,
+            };
+
+            assert_eq!(
+                Ok(pitch.clone()),
+                parse_katakana_solfege_notation(s, &ParseOptions::default())
+            );
+            assert_eq!(s, pitch.katakana_solfege_notation().to_string());
+        }
+
+        let b_flat_4 = Pitch {
+            octave: 4,
+            pitch_class: PitchClass::new(Letter::B, Accidental::Flat),
+            cents: 0,
+        };
+        assert_eq!(
+            Ok(b_flat_4.clone()),
+            parse_katakana_solfege_notation("シ♭4", &ParseOptions::default())
+        );
+        assert_eq!(
+            "シ♭4",
+            b_flat_4
+                .katakana_solfege_notation()
+                .accidental_style(AccidentalStyle::Unicode)
+                .to_string(),
+        );
+
+        let c4 = Pitch {
+            octave: 4,
+            pitch_class: PitchClass::new(Letter::C, Accidental::Natural),
+            cents: 0,
+        };
+        let yamaha = ParseOptions {
+            middle_c: MiddleC::C3,
+            ..Default::default()
+        };
+        assert_eq!(
+            Ok(c4.clone()),
+            parse_katakana_solfege_notation("ド3", &yamaha)
+        );
+        assert_eq!(
+            "ド3",
+            c4.katakana_solfege_notation()
+                .middle_c(MiddleC::C3)
+                .to_string(),
+        );
+
+        for s in ["invalid", "ド", "ハ4", "フア4", "ド256"] {
+            assert_eq!(
+                Err(ParsePitchError),
+                parse_katakana_solfege_notation(s, &ParseOptions::default())
+            );
+        }
+    }
+}
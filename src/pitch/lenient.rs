@@ -0,0 +1,105 @@
+use regex::Regex;
+
+use super::{ParseOptions, ACCIDENTAL_PATTERN};
+
+/// How a notation recases lenient input. Helmholtz and ABC input keep their
+/// case because case selects the octave there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Recase {
+    Keep,
+    Capitalize,
+    Lowercase,
+    Uppercase,
+    /// Lowercase octave words followed by a capitalized note letter, e.g.
+    /// `hiA#`.
+    AlternativeNotation,
+    /// A number with a `Hz` unit.
+    Frequency,
+}
+
+/// Passes `s` through [`canonicalize`] when [`ParseOptions::lenient`] is
+/// set.
+pub(super) fn prepare(s: &str, options: &ParseOptions, recase: Recase) -> String {
+    if options.lenient {
+        return canonicalize(s, recase);
+    }
+
+    s.to_owned()
+}
+
+/// Rewrites `s` into the spelling a notation expects when
+/// [`ParseOptions::lenient`] is set: whitespace is dropped and, where case
+/// carries no meaning, letters are recased.
+pub(super) fn canonicalize(s: &str, recase: Recase) -> String {
+    let s: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+
+    match recase {
+        Recase::Keep => s,
+        Recase::Capitalize => capitalize(&s),
+        Recase::Lowercase => s.to_lowercase(),
+        Recase::Uppercase => s.to_uppercase(),
+        Recase::AlternativeNotation => {
+            let s = s.to_lowercase();
+
+            // The note letter is the last `a`-`g` before the accidental and
+            // cents, which keeps it apart from the `d` in `mid`.
+            match Regex::new(&format!(r"[a-g]{ACCIDENTAL_PATTERN}?([+-]\d+c)?$"))
+                .unwrap()
+                .find(&s)
+            {
+                Some(m) => format!("{}{}", &s[..m.start()], capitalize(m.as_str())),
+                None => s,
+            }
+        }
+        Recase::Frequency => match s.to_lowercase().strip_suffix("hz") {
+            Some(hz) => format!("{hz}Hz"),
+            None => s,
+        },
+    }
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonicalize() {
+        for (recase, s, canonical) in [
+            (Recase::Capitalize, "c4", "C4"),
+            (Recase::Capitalize, "BB4", "Bb4"),
+            (Recase::Capitalize, "f# 3 +5C", "F#3+5c"),
+            (Recase::AlternativeNotation, "MID2C", "mid2C"),
+            (Recase::AlternativeNotation, "mid 2 c", "mid2C"),
+            (Recase::AlternativeNotation, "Hi A#", "hiA#"),
+            (Recase::AlternativeNotation, "HIHIBB", "hihiBb"),
+            (Recase::AlternativeNotation, "lowlowd", "lowlowD"),
+            (Recase::AlternativeNotation, "mid1g-20C", "mid1G-20c"),
+            (Recase::Keep, "c '", "c'"),
+            (Recase::Keep, "C,", "C,"),
+            (Recase::Lowercase, "Cis ''", "cis''"),
+            (Recase::Capitalize, "CIS4", "Cis4"),
+            (Recase::Capitalize, "h 3", "H3"),
+            (Recase::Capitalize, "SOL#3", "Sol#3"),
+            (Recase::Capitalize, "ré4", "Ré4"),
+            (Recase::Keep, "ド 4", "ド4"),
+            (Recase::Uppercase, "c-4", "C-4"),
+            (Recase::Keep, " 6 0", "60"),
+            (Recase::Frequency, "440 HZ", "440Hz"),
+            (Recase::Frequency, "440", "440"),
+        ] {
+            assert_eq!(canonical, canonicalize(s, recase));
+        }
+    }
+}
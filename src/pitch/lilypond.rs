@@ -0,0 +1,210 @@
+use regex::Regex;
+
+use crate::error::{FormatPitchError, ParsePitchClassError, ParsePitchError};
+
+use super::{
+    lenient::{self, Recase},
+    Accidental, FormatOptions, Letter, ParseOptions, Pitch, PitchClass, PitchNotation,
+    SMALL_OCTAVE,
+};
+
+impl Pitch {
+    pub fn lilypond_notation(&self) -> LilyPondNotation<'_> {
+        LilyPondNotation { pitch: self }
+    }
+}
+
+/// LilyPond's Dutch note name, e.g. `cis`, `es` or `bes`.
+fn lilypond_pitch_class_name(pitch_class: &PitchClass) -> String {
+    let letter = pitch_class.letter.as_str().to_ascii_lowercase();
+    let suffix = match (pitch_class.letter, pitch_class.accidental) {
+        (_, Accidental::Natural) => "",
+        (_, Accidental::Sharp) => "is",
+        (_, Accidental::DoubleSharp) => "isis",
+        (Letter::E | Letter::A, Accidental::Flat) => "s",
+        (Letter::E | Letter::A, Accidental::DoubleFlat) => "ses",
+        (_, Accidental::Flat) => "es",
+        (_, Accidental::DoubleFlat) => "eses",
+    };
+
+    format!("{letter}{suffix}")
+}
+
+fn parse_lilypond_pitch_class(s: &str) -> Result<PitchClass, ParsePitchClassError> {
+    let (letter, suffix) = s.split_at(s.chars().next().map_or(0, char::len_utf8));
+
+    let letter = match letter {
+        "c" => Letter::C,
+        "d" => Letter::D,
+        "e" => Letter::E,
+        "f" => Letter::F,
+        "g" => Letter::G,
+        "a" => Letter::A,
+        "b" => Letter::B,
+        _ => return Err(ParsePitchClassError),
+    };
+    let accidental = match (letter, suffix) {
+        (_, "") => Accidental::Natural,
+        (_, "is") => Accidental::Sharp,
+        (_, "isis") => Accidental::DoubleSharp,
+        (_, "es") => Accidental::Flat,
+        (_, "eses") => Accidental::DoubleFlat,
+        (Letter::E | Letter::A, "s") => Accidental::Flat,
+        (Letter::E | Letter::A, "ses") => Accidental::DoubleFlat,
+        _ => return Err(ParsePitchClassError),
+    };
+
+    Ok(PitchClass::new(letter, accidental))
+}
+
+fn parse_lilypond_notation(s: &str) -> Result<Pitch, ParsePitchError> {
+    let Some(caps) = Regex::new(r"^(?<name>[a-g][a-z]*)((?<primes>'+)|(?<commas>,+))?$")
+        .unwrap()
+        .captures(s)
+    else {
+        return Err(ParsePitchError);
+    };
+
+    let pitch_class = parse_lilypond_pitch_class(caps.name("name").unwrap().as_str())?;
+    let octave = match (caps.name("primes"), caps.name("commas")) {
+        (Some(primes), _) => SMALL_OCTAVE + primes.as_str().len() as i32,
+        (_, Some(commas)) => SMALL_OCTAVE - commas.as_str().len() as i32,
+        (None, None) => SMALL_OCTAVE,
+    };
+
+    let octave = Pitch::checked_octave(octave)?;
+
+    Ok(Pitch {
+        octave,
+        pitch_class,
+        cents: 0,
+    })
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LilyPondFormat;
+
+impl PitchNotation for LilyPondFormat {
+    fn name(&self) -> &'static str {
+        "lilypond"
+    }
+
+    fn priority(&self) -> i32 {
+        30
+    }
+
+    fn parse(&self, s: &str, options: &ParseOptions) -> Result<Pitch, ParsePitchError> {
+        parse_lilypond_notation(&lenient::prepare(s, options, Recase::Lowercase))
+    }
+
+    fn format(&self, pitch: &Pitch, _: &FormatOptions) -> Result<String, FormatPitchError> {
+        Ok(pitch.lilypond_notation().to_string())
+    }
+}
+
+/// LilyPond absolute pitch such as `c'`, `cis''` or `bes,`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LilyPondNotation<'a> {
+    pitch: &'a Pitch,
+}
+
+impl std::fmt::Display for LilyPondNotation<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let octave = self.pitch.octave as i32;
+
+        write!(f, "{}", lilypond_pitch_class_name(&self.pitch.pitch_class))?;
+
+        for _ in SMALL_OCTAVE..octave {
+            write!(f, "'")?;
+        }
+        for _ in octave..SMALL_OCTAVE {
+            write!(f, ",")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lilypond_pitch_class_name() {
+        for (letter, names) in [
+            (Letter::C, ["ceses", "ces", "c", "cis", "cisis"]),
+            (Letter::D, ["deses", "des", "d", "dis", "disis"]),
+            (Letter::E, ["eses", "es", "e", "eis", "eisis"]),
+            (Letter::F, ["feses", "fes", "f", "fis", "fisis"]),
+            (Letter::G, ["geses", "ges", "g", "gis", "gisis"]),
+            (Letter::A, ["ases", "as", "a", "ais", "aisis"]),
+            (Letter::B, ["beses", "bes", "b", "bis", "bisis"]),
+        ] {
+            for (accidental, name) in [
+                Accidental::DoubleFlat,
+                Accidental::Flat,
+                Accidental::Natural,
+                Accidental::Sharp,
+                Accidental::DoubleSharp,
+            ]
+            .into_iter()
+            .zip(names)
+            {
+                let pitch_class = PitchClass::new(letter, accidental);
+
+                assert_eq!(name, lilypond_pitch_class_name(&pitch_class));
+                assert_eq!(Ok(pitch_class), parse_lilypond_pitch_class(name));
+            }
+        }
+
+        assert_eq!(
+            parse_lilypond_pitch_class("es"),
+            parse_lilypond_pitch_class("ees")
+        );
+        assert_eq!(
+            parse_lilypond_pitch_class("ases"),
+            parse_lilypond_pitch_class("aeses")
+        );
+
+        for s in ["", "h", "bs", "cs", "C", "cis'", "ciss"] {
+            assert_eq!(Err(ParsePitchClassError), parse_lilypond_pitch_class(s));
+        }
+    }
+
+    #[test]
+    fn test_lilypond_notation() {
+        for (octave, pitch_class, s) in [
+            (-1, PitchClass::new(Letter::C, Accidental::Natural), "c,,,,"),
+            (2, PitchClass::new(Letter::B, Accidental::Flat), "bes,"),
+            (3, PitchClass::new(Letter::C, Accidental::Natural), "c"),
+            (3, PitchClass::new(Letter::E, Accidental::Flat), "es"),
+            (4, PitchClass::new(Letter::C, Accidental::Natural), "c'"),
+            (5, PitchClass::new(Letter::C, Accidental::Sharp), "cis''"),
+            (
+                5,
+                PitchClass::new(Letter::A, Accidental::DoubleFlat),
+                "ases''",
+            ),
+        ] {
+            let pitch = Pitch {
+                octave,
+                pitch_class,
+                cents: 0,
+            };
+
+            assert_eq!(Ok(pitch.clone()), parse_lilypond_notation(s));
+            assert_eq!(s, pitch.lilypond_notation().to_string());
+        }
+
+        for s in [
+            "invalid",
+            "C'",
+            "c',",
+            &format!("c{}", ",".repeat(259)),
+            "cis4",
+            "c'-20c",
+        ] {
+            assert_eq!(Err(ParsePitchError), parse_lilypond_notation(s));
+        }
+    }
+}
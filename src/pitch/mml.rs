@@ -0,0 +1,224 @@
+use std::iter::Peekable;
+use std::str::Chars;
+
+use crate::error::ParsePitchError;
+
+use super::{Accidental, Letter, Pitch, PitchClass};
+
+/// Octave selected by `o` before the first octave command, as in most MML
+/// dialects.
+const DEFAULT_OCTAVE: i32 = 4;
+
+/// Walks an MML string such as `o4 c+ d e- > c` and returns its notes in
+/// order.
+///
+/// `o` sets the octave, `>` raises it and `<` lowers it. Accidentals are `+`
+/// or `#` for sharp and `-` for flat. Note lengths, dots, ties, rests and
+/// the `l`, `t`, `v`, `q` and `@` commands are skipped.
+pub fn parse_mml(s: &str) -> Result<Vec<Pitch>, ParsePitchError> {
+    let mut chars = s.chars().peekable();
+    let mut octave = DEFAULT_OCTAVE;
+    let mut pitches = Vec::new();
+
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {}
+            'o' | 'O' => octave = parse_number(&mut chars).ok_or(ParsePitchError)?,
+            '>' => octave = octave.checked_add(1).ok_or(ParsePitchError)?,
+            '<' => octave = octave.checked_sub(1).ok_or(ParsePitchError)?,
+            'A'..='G' | 'a'..='g' => {
+                let letter = match c.to_ascii_lowercase() {
+                    'c' => Letter::C,
+                    'd' => Letter::D,
+                    'e' => Letter::E,
+                    'f' => Letter::F,
+                    'g' => Letter::G,
+                    'a' => Letter::A,
+                    _ => Letter::B,
+                };
+                let accidental = parse_accidental(&mut chars)?;
+
+                let octave = Pitch::checked_octave(octave)?;
+
+                pitches.push(Pitch {
+                    octave,
+                    pitch_class: PitchClass::new(letter, accidental),
+                    cents: 0,
+                });
+                skip_length(&mut chars);
+            }
+            'r' | 'l' | 't' | 'v' | 'q' | 'R' | 'L' | 'T' | 'V' | 'Q' | '@' | '&' | '^' => {
+                skip_length(&mut chars)
+            }
+            _ => return Err(ParsePitchError),
+        }
+    }
+
+    Ok(pitches)
+}
+
+fn parse_number(chars: &mut Peekable<Chars>) -> Option<i32> {
+    let mut n: Option<i32> = None;
+
+    while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+        chars.next();
+        n = Some(n.unwrap_or(0).checked_mul(10)?.checked_add(digit as i32)?);
+    }
+
+    n
+}
+
+fn parse_accidental(chars: &mut Peekable<Chars>) -> Result<Accidental, ParsePitchError> {
+    let mut offset = 0;
+
+    while let Some(c) = chars.next_if(|c| matches!(c, '+' | '#' | '-')) {
+        offset += if c == '-' { -1 } else { 1 };
+    }
+
+    match offset {
+        -2 => Ok(Accidental::DoubleFlat),
+        -1 => Ok(Accidental::Flat),
+        0 => Ok(Accidental::Natural),
+        1 => Ok(Accidental::Sharp),
+        2 => Ok(Accidental::DoubleSharp),
+        _ => Err(ParsePitchError),
+    }
+}
+
+fn skip_length(chars: &mut Peekable<Chars>) {
+    while chars.next_if(|c| c.is_ascii_digit() || *c == '.').is_some() {}
+}
+
+/// MML for a sequence of pitches, e.g. `o4 c+ d e- > c`. Steps of one octave
+/// are written with `>` and `<`, larger ones with `o`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MmlNotation<'a> {
+    pitches: &'a [Pitch],
+}
+
+impl<'a> MmlNotation<'a> {
+    pub fn new(pitches: &'a [Pitch]) -> Self {
+        MmlNotation { pitches }
+    }
+}
+
+impl std::fmt::Display for MmlNotation<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut current_octave = None;
+
+        for (i, pitch) in self.pitches.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+
+            let octave = pitch.octave as i32;
+
+            match current_octave.map(|current| octave - current) {
+                Some(0) => {}
+                Some(1) => write!(f, "> ")?,
+                Some(-1) => write!(f, "< ")?,
+                _ if octave < 0 => {
+                    write!(f, "o0 ")?;
+                    for _ in octave..0 {
+                        write!(f, "< ")?;
+                    }
+                }
+                _ => write!(f, "o{octave} ")?,
+            }
+            current_octave = Some(octave);
+
+            let accidental = match pitch.pitch_class.accidental {
+                Accidental::DoubleFlat => "--",
+                Accidental::Flat => "-",
+                Accidental::Natural => "",
+                Accidental::Sharp => "+",
+                Accidental::DoubleSharp => "++",
+            };
+
+            write!(
+                f,
+                "{}{accidental}",
+                pitch.pitch_class.letter.as_str().to_ascii_lowercase(),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pitch(octave: i16, letter: Letter, accidental: Accidental) -> Pitch {
+        Pitch {
+            octave,
+            pitch_class: PitchClass::new(letter, accidental),
+            cents: 0,
+        }
+    }
+
+    #[test]
+    fn test_mml() {
+        for (pitches, s) in [
+            (
+                vec![
+                    pitch(4, Letter::C, Accidental::Sharp),
+                    pitch(4, Letter::D, Accidental::Natural),
+                    pitch(4, Letter::E, Accidental::Flat),
+                    pitch(5, Letter::C, Accidental::Natural),
+                ],
+                "o4 c+ d e- > c",
+            ),
+            (
+                vec![
+                    pitch(3, Letter::A, Accidental::Natural),
+                    pitch(2, Letter::B, Accidental::DoubleFlat),
+                    pitch(6, Letter::F, Accidental::DoubleSharp),
+                    pitch(6, Letter::G, Accidental::Natural),
+                ],
+                "o3 a < b-- o6 f++ g",
+            ),
+            (
+                vec![
+                    pitch(-1, Letter::C, Accidental::Natural),
+                    pitch(0, Letter::C, Accidental::Natural),
+                ],
+                "o0 < c > c",
+            ),
+            (vec![], ""),
+        ] {
+            assert_eq!(Ok(pitches.clone()), parse_mml(s));
+            assert_eq!(s, MmlNotation::new(&pitches).to_string());
+        }
+    }
+
+    #[test]
+    fn test_parse_mml() {
+        assert_eq!(
+            Ok(vec![
+                pitch(4, Letter::C, Accidental::Natural),
+                pitch(4, Letter::F, Accidental::Sharp),
+                pitch(5, Letter::B, Accidental::Flat),
+                pitch(5, Letter::A, Accidental::Natural),
+            ]),
+            parse_mml("t120 l8 @1 v12 C4. r8 f#16 & >B-2^8 q7 a"),
+        );
+        assert_eq!(
+            Ok(vec![pitch(2, Letter::E, Accidental::Natural)]),
+            parse_mml("o2e"),
+        );
+
+        for s in [
+            "o4 h",
+            "o c",
+            "c+++",
+            "o300 c",
+            &format!("o0 {} c", "<".repeat(256)),
+            "o2147483647 > c",
+            "o99999999999 c",
+        ] {
+            assert_eq!(Err(ParsePitchError), parse_mml(s));
+        }
+    }
+}
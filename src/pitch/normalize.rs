@@ -0,0 +1,53 @@
+/// Folds input typed through an IME or copied from a document into the
+/// ASCII forms the parsers expect.
+///
+/// Surrounding whitespace (including the ideographic space) is removed,
+/// full-width ASCII such as `ｍｉｄ２Ｃ` or `＃` is folded to its ASCII form, and
+/// common look-alikes such as `−`, `’` or a Cyrillic `С` are replaced.
+/// Case is left untouched.
+pub fn normalize(s: &str) -> String {
+    s.trim().chars().map(fold_char).collect()
+}
+
+fn fold_char(c: char) -> char {
+    match c {
+        '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap(),
+        '\u{2010}'..='\u{2013}' | '\u{2212}' | '\u{FE63}' => '-',
+        '\u{2018}' | '\u{2019}' | '\u{02BC}' | '\u{00B4}' => '\'',
+        'А' | 'Α' => 'A',
+        'В' | 'Β' => 'B',
+        'С' => 'C',
+        'Е' | 'Ε' => 'E',
+        'Н' | 'Η' => 'H',
+        c => c,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        for (s, normalized) in [
+            ("C4", "C4"),
+            ("ｍｉｄ２Ｃ", "mid2C"),
+            ("hiＡ＃", "hiA#"),
+            ("Ｃ４", "C4"),
+            ("Ｂｂ３", "Bb3"),
+            ("\u{3000}C4\u{3000}", "C4"),
+            (" \tmid2C\n", "mid2C"),
+            ("C\u{2212}1", "C-1"),
+            ("A4\u{2013}30c", "A4-30c"),
+            ("c\u{2019}", "c'"),
+            ("\u{0421}4", "C4"),
+            ("\u{0392}b3", "Bb3"),
+            ("２６１．６３Ｈｚ", "261.63Hz"),
+            ("一点ハ", "一点ハ"),
+            ("F♯4", "F♯4"),
+            ("c4", "c4"),
+        ] {
+            assert_eq!(normalized, normalize(s));
+        }
+    }
+}
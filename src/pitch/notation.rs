@@ -0,0 +1,537 @@
+use std::sync::{LazyLock, RwLock};
+
+use crate::error::{FormatPitchError, ParsePitchClassError, ParsePitchError};
+
+use super::{
+    normalize, AbcFormat, AccidentalStyle, AlternativeFormat, AlternativeStyle, CsoundOctFormat,
+    CsoundPchFormat, FrequencyFormat, GermanFormat, HelmholtzFormat, HelmholtzStyle,
+    JapaneseFormat, KatakanaSolfegeFormat, LilyPondFormat, MiddleC, MidiFormat, OctaveBoundary,
+    ParseOptions, PianoKeyFormat, Pitch, PitchClass, PitchFormat, PitchWithFormat,
+    ScientificFormat, SolfegeFormat, SolfegeVariant, TrackerFormat, DEFAULT_A4_FREQUENCY,
+};
+
+/// Settings for [`PitchNotation::format`]. Each notation uses the ones that
+/// apply to it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FormatOptions {
+    pub middle_c: MiddleC,
+    pub a4_frequency: f64,
+    pub octave_boundary: OctaveBoundary,
+    pub accidental_style: AccidentalStyle,
+    pub solfege_variant: SolfegeVariant,
+    pub helmholtz_style: HelmholtzStyle,
+    pub alternative_style: AlternativeStyle,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        FormatOptions {
+            middle_c: MiddleC::default(),
+            a4_frequency: DEFAULT_A4_FREQUENCY,
+            octave_boundary: OctaveBoundary::default(),
+            accidental_style: AccidentalStyle::default(),
+            solfege_variant: SolfegeVariant::default(),
+            helmholtz_style: HelmholtzStyle::default(),
+            alternative_style: AlternativeStyle::default(),
+        }
+    }
+}
+
+/// A way of writing a [`Pitch`] that can be added to a [`NotationRegistry`],
+/// including notations defined outside this crate.
+pub trait PitchNotation: Send + Sync {
+    /// Unique name, e.g. `scientific`. [`PitchFormat`] and the command line's
+    /// `--from` and `--to` refer to the notation by it.
+    fn name(&self) -> &'static str;
+
+    /// Detection order; lower priorities are tried first. The built-in
+    /// notations use multiples of 10, from 0 for `scientific` to 140 for
+    /// `frequency`, so a notation can be placed between any two of them.
+    fn priority(&self) -> i32;
+
+    /// Reads `s`, which the registry has already passed through
+    /// [`normalize`].
+    fn parse(&self, s: &str, options: &ParseOptions) -> Result<Pitch, ParsePitchError>;
+
+    /// Notations that cannot write cents, such as MIDI note numbers or
+    /// LilyPond, write the pitch without them.
+    fn format(&self, pitch: &Pitch, options: &FormatOptions) -> Result<String, FormatPitchError>;
+
+    /// Reads a note name without an octave, e.g. `C#`, for notations that
+    /// have them. Like [`PitchNotation::parse`], `s` is already normalized.
+    fn parse_pitch_class(
+        &self,
+        _s: &str,
+        _options: &ParseOptions,
+    ) -> Result<PitchClass, ParsePitchClassError> {
+        Err(ParsePitchClassError)
+    }
+
+    fn format_pitch_class(
+        &self,
+        _pitch_class: &PitchClass,
+        _options: &FormatOptions,
+    ) -> Result<String, FormatPitchError> {
+        Err(FormatPitchError)
+    }
+}
+
+/// A pitch, or a note name without an octave, as read from some input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reading {
+    Pitch(Pitch),
+    PitchClass(PitchClass),
+}
+
+impl std::fmt::Display for Reading {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Reading::Pitch(pitch) => write!(f, "{pitch}"),
+            Reading::PitchClass(pitch_class) => write!(f, "{pitch_class}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadingWithFormat {
+    pub reading: Reading,
+    pub format: PitchFormat,
+}
+
+static GLOBAL: LazyLock<RwLock<NotationRegistry>> =
+    LazyLock::new(|| RwLock::new(NotationRegistry::default()));
+
+/// The notations to detect and convert between, kept in priority order.
+/// [`NotationRegistry::default`] holds the built-in notations.
+pub struct NotationRegistry {
+    notations: Vec<Box<dyn PitchNotation>>,
+    preferred: Vec<String>,
+}
+
+impl NotationRegistry {
+    /// A registry without any notations.
+    pub fn new() -> Self {
+        NotationRegistry {
+            notations: Vec::new(),
+            preferred: Vec::new(),
+        }
+    }
+
+    /// The registry used by [`PitchWithFormat::parse`], [`PitchFormat::parse`]
+    /// and `str::parse::<Pitch>()`. It starts out as the default one.
+    pub fn global() -> &'static RwLock<NotationRegistry> {
+        &GLOBAL
+    }
+
+    /// Adds `notation` after those with the same or a lower priority,
+    /// replacing any notation with the same name.
+    pub fn register(&mut self, notation: impl PitchNotation + 'static) {
+        self.notations
+            .retain(|registered| registered.name() != notation.name());
+
+        let index = self
+            .notations
+            .partition_point(|registered| registered.priority() <= notation.priority());
+        self.notations.insert(index, Box::new(notation));
+    }
+
+    /// Tries the notation called `name` before any other, after those
+    /// preferred earlier.
+    pub fn prefer(&mut self, name: &str) {
+        if !self.preferred.iter().any(|preferred| preferred == name) {
+            self.preferred.push(name.to_owned());
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn PitchNotation> {
+        self.notations
+            .iter()
+            .find(|notation| notation.name() == name)
+            .map(|notation| notation.as_ref())
+    }
+
+    /// The notations in detection order: preferred ones first, then the rest
+    /// by priority.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn PitchNotation> {
+        let preferred = self.preferred.iter().filter_map(|name| self.get(name));
+        let rest = self
+            .notations
+            .iter()
+            .filter(|notation| !self.preferred.iter().any(|name| name == notation.name()))
+            .map(|notation| notation.as_ref());
+
+        preferred.chain(rest)
+    }
+
+    /// Reads `s` in the first notation that accepts it.
+    pub fn parse(
+        &self,
+        s: &str,
+        options: &ParseOptions,
+    ) -> Result<PitchWithFormat, ParsePitchError> {
+        self.parse_all(s, options)
+            .into_iter()
+            .next()
+            .ok_or(ParsePitchError)
+    }
+
+    /// Every reading of `s` as a pitch, in detection order.
+    pub fn parse_all(&self, s: &str, options: &ParseOptions) -> Vec<PitchWithFormat> {
+        self.find_all(s, options, |notation, s, options| {
+            let pitch = notation.parse(s, options).ok()?;
+
+            Some(PitchWithFormat {
+                pitch,
+                format: PitchFormat::of(notation),
+            })
+        })
+    }
+
+    /// Every reading of `s` in detection order, as a pitch or, in notations
+    /// that name them, as a note name without an octave.
+    pub fn read_all(&self, s: &str, options: &ParseOptions) -> Vec<ReadingWithFormat> {
+        self.find_all(s, options, |notation, s, options| {
+            let reading = match notation.parse(s, options) {
+                Ok(pitch) => Reading::Pitch(pitch),
+                Err(_) => Reading::PitchClass(notation.parse_pitch_class(s, options).ok()?),
+            };
+
+            Some(ReadingWithFormat {
+                reading,
+                format: PitchFormat::of(notation),
+            })
+        })
+    }
+
+    /// What `read` finds in each notation for `s`, normalized. Lenient
+    /// readings are only looked for when there is no strict one, so
+    /// [`ParseOptions::lenient`] never changes how valid input reads.
+    fn find_all<T>(
+        &self,
+        s: &str,
+        options: &ParseOptions,
+        read: impl Fn(&dyn PitchNotation, &str, &ParseOptions) -> Option<T>,
+    ) -> Vec<T> {
+        let s = normalize(s);
+        let strict = ParseOptions {
+            lenient: false,
+            ..options.clone()
+        };
+
+        let found: Vec<T> = self
+            .iter()
+            .filter_map(|notation| read(notation, &s, &strict))
+            .collect();
+        if !found.is_empty() || !options.lenient {
+            return found;
+        }
+
+        self.iter()
+            .filter_map(|notation| read(notation, &s, options))
+            .collect()
+    }
+}
+
+/// Runs `parse` on `s` once it is normalized, strictly first, like
+/// [`NotationRegistry::parse`].
+pub(super) fn parse_with<T, E>(
+    s: &str,
+    options: &ParseOptions,
+    parse: impl Fn(&str, &ParseOptions) -> Result<T, E>,
+) -> Result<T, E> {
+    let s = normalize(s);
+    let strict = ParseOptions {
+        lenient: false,
+        ..options.clone()
+    };
+
+    match parse(&s, &strict) {
+        Err(_) if options.lenient => parse(&s, options),
+        result => result,
+    }
+}
+
+impl Default for NotationRegistry {
+    fn default() -> Self {
+        let mut registry = NotationRegistry::new();
+
+        registry.register(ScientificFormat);
+        registry.register(AlternativeFormat);
+        registry.register(HelmholtzFormat);
+        registry.register(LilyPondFormat);
+        registry.register(AbcFormat);
+        registry.register(GermanFormat);
+        registry.register(JapaneseFormat);
+        registry.register(KatakanaSolfegeFormat);
+        registry.register(SolfegeFormat);
+        registry.register(TrackerFormat);
+        registry.register(CsoundPchFormat);
+        registry.register(CsoundOctFormat);
+        registry.register(MidiFormat);
+        registry.register(PianoKeyFormat);
+        registry.register(FrequencyFormat);
+
+        registry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Cents above C0, e.g. `5700¢` for A4.
+    struct CentsNotation;
+
+    impl PitchNotation for CentsNotation {
+        fn name(&self) -> &'static str {
+            "cents"
+        }
+
+        fn priority(&self) -> i32 {
+            MidiFormat.priority() - 5
+        }
+
+        fn parse(&self, s: &str, _: &ParseOptions) -> Result<Pitch, ParsePitchError> {
+            let cents: i32 = s.strip_suffix('¢').ok_or(ParsePitchError)?.parse()?;
+
+            if cents % 100 != 0 {
+                return Err(ParsePitchError);
+            }
+
+            Ok(Pitch::from_semitones(cents / 100)?)
+        }
+
+        fn format(&self, pitch: &Pitch, _: &FormatOptions) -> Result<String, FormatPitchError> {
+            Ok(format!("{}¢", pitch.semitones() * 100 + pitch.cents as i32))
+        }
+    }
+
+    fn names(registry: &NotationRegistry) -> Vec<&'static str> {
+        registry.iter().map(|notation| notation.name()).collect()
+    }
+
+    #[test]
+    fn test_builtin_notations() {
+        let registry = NotationRegistry::default();
+
+        assert_eq!(
+            vec![
+                "scientific",
+                "alternative",
+                "helmholtz",
+                "lilypond",
+                "abc",
+                "german",
+                "japanese",
+                "katakana-solfege",
+                "solfege",
+                "tracker",
+                "csound-pch",
+                "csound-oct",
+                "midi",
+                "piano-key",
+                "frequency",
+            ],
+            names(&registry),
+        );
+
+        let a4: Pitch = "A4".parse().unwrap();
+        for (name, s) in [
+            ("scientific", "A4"),
+            ("alternative", "hiA"),
+            ("helmholtz", "a'"),
+            ("midi", "69"),
+            ("frequency", "440.00Hz"),
+        ] {
+            let notation = registry.get(name).unwrap();
+
+            assert_eq!(Ok(a4.clone()), notation.parse(s, &ParseOptions::default()));
+            assert_eq!(
+                Ok(s.to_owned()),
+                notation.format(&a4, &FormatOptions::default())
+            );
+        }
+
+        let options = FormatOptions {
+            middle_c: MiddleC::C3,
+            accidental_style: AccidentalStyle::Unicode,
+            octave_boundary: OctaveBoundary::C,
+            ..Default::default()
+        };
+        let a_sharp_4: Pitch = "A#4".parse().unwrap();
+        assert_eq!(
+            Ok("A♯3".to_owned()),
+            PitchFormat::of(&ScientificFormat).format(&a_sharp_4, &options),
+        );
+        assert_eq!(
+            Ok("mid2A♯".to_owned()),
+            PitchFormat::of(&AlternativeFormat).format(&a_sharp_4, &options),
+        );
+
+        let options = FormatOptions {
+            helmholtz_style: HelmholtzStyle::Indices,
+            alternative_style: AlternativeStyle::Numeric,
+            ..Default::default()
+        };
+        let c7: Pitch = "C7".parse().unwrap();
+        assert_eq!(
+            Ok("c⁴".to_owned()),
+            PitchFormat::of(&HelmholtzFormat).format(&c7, &options),
+        );
+        assert_eq!(
+            Ok("hi3C".to_owned()),
+            PitchFormat::of(&AlternativeFormat).format(&c7, &options),
+        );
+
+        let c9: Pitch = "C9".parse().unwrap();
+        assert_eq!(
+            Err(FormatPitchError),
+            PitchFormat::of(&PianoKeyFormat).format(&c9, &FormatOptions::default()),
+        );
+    }
+
+    #[test]
+    fn test_register_notation() {
+        let mut registry = NotationRegistry::default();
+        registry.register(CentsNotation);
+
+        let names = names(&registry);
+        let position = |name| names.iter().position(|&n| n == name).unwrap();
+        assert_eq!(position("csound-oct") + 1, position("cents"));
+        assert_eq!(position("cents") + 1, position("midi"));
+
+        assert_eq!(
+            Ok(PitchWithFormat {
+                pitch: "A4".parse().unwrap(),
+                format: PitchFormat::of(&CentsNotation),
+            }),
+            registry.parse("5700¢", &ParseOptions::default()),
+        );
+        for s in [" 5700¢ ", "５７００¢"] {
+            assert_eq!(
+                registry.parse("5700¢", &ParseOptions::default()),
+                registry.parse(s, &ParseOptions::default()),
+            );
+        }
+
+        assert_eq!(
+            Ok("6000¢".to_owned()),
+            registry
+                .get("cents")
+                .unwrap()
+                .format(&"C5".parse().unwrap(), &FormatOptions::default()),
+        );
+
+        registry.register(CentsNotation);
+        assert_eq!(1, registry.iter().filter(|n| n.name() == "cents").count());
+        assert!(registry.get("unknown").is_none());
+        assert!(NotationRegistry::new()
+            .parse("C4", &ParseOptions::default())
+            .is_err());
+    }
+
+    #[test]
+    fn test_prefer_notation() {
+        let mut registry = NotationRegistry::default();
+        let options = ParseOptions::default();
+
+        let readings = |registry: &NotationRegistry| -> Vec<(&'static str, String)> {
+            registry
+                .parse_all("60", &options)
+                .into_iter()
+                .map(|c| (c.format.name(), c.pitch.to_string()))
+                .collect()
+        };
+
+        assert_eq!(
+            vec![("midi", "C4".to_owned()), ("piano-key", "G#5".to_owned())],
+            readings(&registry),
+        );
+
+        registry.prefer("piano-key");
+        registry.prefer("unknown");
+        registry.prefer("piano-key");
+        assert_eq!(
+            vec![("piano-key", "G#5".to_owned()), ("midi", "C4".to_owned())],
+            readings(&registry),
+        );
+        assert_eq!("piano-key", names(&registry)[0]);
+        assert_eq!(15, names(&registry).len());
+    }
+
+    #[test]
+    fn test_read_all() {
+        let registry = NotationRegistry::default();
+        let readings = |s, options| -> Vec<(&'static str, String)> {
+            registry
+                .read_all(s, options)
+                .into_iter()
+                .map(|r| (r.format.name(), r.reading.to_string()))
+                .collect()
+        };
+        let strict = ParseOptions::default();
+        let lenient = ParseOptions {
+            lenient: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            vec![
+                ("scientific", "C".to_owned()),
+                ("helmholtz", "C2".to_owned()),
+                ("abc", "C4".to_owned()),
+                ("german", "C".to_owned()),
+            ],
+            readings("C", &strict),
+        );
+        assert_eq!(
+            vec![
+                ("helmholtz", "C3".to_owned()),
+                ("lilypond", "C3".to_owned())
+            ],
+            readings("c", &strict)[..2],
+        );
+        assert_eq!(readings("c", &strict), readings("c", &lenient));
+        assert_eq!(
+            vec![("german", "C#".to_owned())],
+            readings("Ｃｉｓ", &strict),
+        );
+        assert_eq!(
+            Some(("scientific", "C4".to_owned())),
+            readings("c 4", &lenient).into_iter().next(),
+        );
+        assert!(readings("c 4", &strict).is_empty());
+    }
+
+    #[test]
+    fn test_global_registry() {
+        assert_eq!(None, PitchFormat::named("cents"));
+
+        NotationRegistry::global()
+            .write()
+            .unwrap()
+            .register(CentsNotation);
+
+        assert_eq!(
+            Some(PitchFormat::of(&CentsNotation)),
+            PitchFormat::named("cents")
+        );
+        assert_eq!(
+            Some(PitchFormat::of(&MidiFormat)),
+            PitchFormat::named("midi")
+        );
+        assert_eq!(Ok("A4".parse().unwrap()), "5700¢".parse::<Pitch>());
+        assert_eq!(
+            Ok(PitchFormat::of(&CentsNotation)),
+            "5700¢".parse::<PitchWithFormat>().map(|p| p.format),
+        );
+        assert_eq!(
+            Ok("6000¢".to_owned()),
+            PitchFormat::of(&CentsNotation)
+                .format(&"C5".parse().unwrap(), &FormatOptions::default()),
+        );
+
+        *NotationRegistry::global().write().unwrap() = NotationRegistry::default();
+        assert_eq!(None, PitchFormat::named("cents"));
+    }
+}
@@ -0,0 +1,422 @@
+use crate::error::ParsePitchClassError;
+
+use super::{
+    german, japanese,
+    lenient::{self, Recase},
+    normalize, notation, solfege, AccidentalStyle, FormatOptions, Letter, ParseOptions, PitchClass,
+    SolfegeVariant,
+};
+
+/// Names for a [`PitchClass`] on its own, without an octave.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PitchClassFormat {
+    /// `C#`, `Bb`
+    Letter,
+    /// `Cis`, `B`, `H`
+    German,
+    /// `Ré`, `Sol#`
+    Solfege,
+    /// `嬰ハ`, `変ロ`
+    Japanese,
+    /// `ド#`, `シb`
+    KatakanaSolfege,
+}
+
+impl PitchClassFormat {
+    /// Formats tried, in order, by [`PitchClassWithFormat::parse`].
+    pub const DETECTION_ORDER: [PitchClassFormat; 5] = [
+        PitchClassFormat::Letter,
+        PitchClassFormat::German,
+        PitchClassFormat::Japanese,
+        PitchClassFormat::KatakanaSolfege,
+        PitchClassFormat::Solfege,
+    ];
+
+    /// Parses `s` in this format after passing it through [`normalize`].
+    pub fn parse(
+        &self,
+        s: &str,
+        options: &ParseOptions,
+    ) -> Result<PitchClass, ParsePitchClassError> {
+        notation::parse_with(s, options, |s, options| self.parse_normalized(s, options))
+    }
+
+    pub(super) fn parse_normalized(
+        &self,
+        s: &str,
+        options: &ParseOptions,
+    ) -> Result<PitchClass, ParsePitchClassError> {
+        if options.lenient {
+            return self.parse_canonical(&lenient::canonicalize(s, self.recase()));
+        }
+
+        self.parse_canonical(s)
+    }
+
+    fn parse_canonical(&self, s: &str) -> Result<PitchClass, ParsePitchClassError> {
+        match self {
+            PitchClassFormat::Letter => s.parse(),
+            PitchClassFormat::German => german::parse_german_pitch_class(s),
+            PitchClassFormat::Solfege => parse_syllable_pitch_class(
+                s,
+                &["Do", "Ut", "Re", "Ré", "Mi", "Fa", "Sol", "La", "Si"],
+                solfege::parse_solfege_syllable,
+            ),
+            PitchClassFormat::Japanese => japanese::parse_japanese_pitch_class(s),
+            PitchClassFormat::KatakanaSolfege => parse_syllable_pitch_class(
+                s,
+                &["ド", "レ", "ミ", "ファ", "ソ", "ラ", "シ"],
+                japanese::parse_katakana_solfege_syllable,
+            ),
+        }
+    }
+
+    /// Writes `pitch_class` the way [`PitchNotation::format_pitch_class`]
+    /// does.
+    ///
+    /// [`PitchNotation::format_pitch_class`]: super::PitchNotation::format_pitch_class
+    pub(super) fn format(&self, pitch_class: &PitchClass, options: &FormatOptions) -> String {
+        pitch_class
+            .notation(*self)
+            .accidental_style(options.accidental_style)
+            .solfege_variant(options.solfege_variant)
+            .to_string()
+    }
+
+    /// Recased like the full-pitch notation that spells pitch classes this
+    /// way.
+    fn recase(&self) -> Recase {
+        match self {
+            PitchClassFormat::Letter | PitchClassFormat::German | PitchClassFormat::Solfege => {
+                Recase::Capitalize
+            }
+            PitchClassFormat::Japanese | PitchClassFormat::KatakanaSolfege => Recase::Keep,
+        }
+    }
+}
+
+/// A syllable followed by an ASCII or Unicode accidental, e.g. `Sol#`.
+fn parse_syllable_pitch_class(
+    s: &str,
+    syllables: &[&str],
+    parse_syllable: fn(&str) -> Result<Letter, ParsePitchClassError>,
+) -> Result<PitchClass, ParsePitchClassError> {
+    syllables
+        .iter()
+        .find_map(|syllable| {
+            let accidental = s.strip_prefix(syllable)?;
+            let letter = parse_syllable(syllable).ok()?;
+
+            format!("{letter}{accidental}").parse().ok()
+        })
+        .ok_or(ParsePitchClassError)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PitchClassWithFormat {
+    pub pitch_class: PitchClass,
+    pub format: PitchClassFormat,
+}
+
+impl PitchClassWithFormat {
+    pub fn parse(s: &str, options: &ParseOptions) -> Result<Self, ParsePitchClassError> {
+        Self::candidates(s, options)
+            .next()
+            .ok_or(ParsePitchClassError)
+    }
+
+    /// Every reading of `s`, in [`PitchClassFormat::DETECTION_ORDER`], e.g.
+    /// `B` as a letter name and as German for B♭.
+    pub fn parse_all(s: &str, options: &ParseOptions) -> Vec<Self> {
+        Self::candidates(s, options).collect()
+    }
+
+    /// Readings of `s`, normalized. Lenient ones are only looked for when
+    /// there is no strict one.
+    fn candidates(s: &str, options: &ParseOptions) -> impl Iterator<Item = PitchClassWithFormat> {
+        let s = normalize(s);
+        let read = |options: &ParseOptions| -> Vec<_> {
+            PitchClassFormat::DETECTION_ORDER
+                .into_iter()
+                .filter_map(|format| {
+                    let pitch_class = format.parse_normalized(&s, options).ok()?;
+
+                    Some(PitchClassWithFormat {
+                        pitch_class,
+                        format,
+                    })
+                })
+                .collect()
+        };
+
+        let mut candidates = read(&ParseOptions {
+            lenient: false,
+            ..options.clone()
+        });
+        if candidates.is_empty() && options.lenient {
+            candidates = read(options);
+        }
+
+        candidates.into_iter()
+    }
+}
+
+impl std::str::FromStr for PitchClassWithFormat {
+    type Err = ParsePitchClassError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PitchClassWithFormat::parse(s, &ParseOptions::default())
+    }
+}
+
+impl PitchClass {
+    pub fn notation(&self, format: PitchClassFormat) -> PitchClassNotation<'_> {
+        PitchClassNotation {
+            pitch_class: self,
+            format,
+            accidental_style: AccidentalStyle::default(),
+            solfege_variant: SolfegeVariant::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PitchClassNotation<'a> {
+    pitch_class: &'a PitchClass,
+    format: PitchClassFormat,
+    accidental_style: AccidentalStyle,
+    solfege_variant: SolfegeVariant,
+}
+
+impl PitchClassNotation<'_> {
+    pub fn accidental_style(mut self, accidental_style: AccidentalStyle) -> Self {
+        self.accidental_style = accidental_style;
+        self
+    }
+
+    pub fn solfege_variant(mut self, solfege_variant: SolfegeVariant) -> Self {
+        self.solfege_variant = solfege_variant;
+        self
+    }
+}
+
+impl std::fmt::Display for PitchClassNotation<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let letter = self.pitch_class.letter;
+        let accidental = self.pitch_class.accidental.symbol(self.accidental_style);
+
+        match self.format {
+            PitchClassFormat::Letter => write!(f, "{letter}{accidental}"),
+            PitchClassFormat::German => {
+                write!(f, "{}", german::german_pitch_class_name(self.pitch_class))
+            }
+            PitchClassFormat::Solfege => write!(
+                f,
+                "{}{accidental}",
+                solfege::solfege_syllable(letter, self.solfege_variant),
+            ),
+            PitchClassFormat::Japanese => {
+                write!(
+                    f,
+                    "{}",
+                    japanese::japanese_pitch_class_name(self.pitch_class)
+                )
+            }
+            PitchClassFormat::KatakanaSolfege => write!(
+                f,
+                "{}{accidental}",
+                japanese::katakana_solfege_syllable(letter),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pitch::Accidental;
+
+    #[test]
+    fn test_pitch_class_notation() {
+        for (pitch_class, letter, german, solfege, japanese, katakana_solfege) in [
+            (
+                PitchClass::new(Letter::C, Accidental::Natural),
+                "C",
+                "C",
+                "Do",
+                "ハ",
+                "ド",
+            ),
+            (
+                PitchClass::new(Letter::C, Accidental::Sharp),
+                "C#",
+                "Cis",
+                "Do#",
+                "嬰ハ",
+                "ド#",
+            ),
+            (
+                PitchClass::new(Letter::E, Accidental::Flat),
+                "Eb",
+                "Es",
+                "Mib",
+                "変ホ",
+                "ミb",
+            ),
+            (
+                PitchClass::new(Letter::F, Accidental::DoubleSharp),
+                "F##",
+                "Fisis",
+                "Fa##",
+                "重嬰ヘ",
+                "ファ##",
+            ),
+            (
+                PitchClass::new(Letter::G, Accidental::Sharp),
+                "G#",
+                "Gis",
+                "Sol#",
+                "嬰ト",
+                "ソ#",
+            ),
+            (
+                PitchClass::new(Letter::B, Accidental::Flat),
+                "Bb",
+                "B",
+                "Sib",
+                "変ロ",
+                "シb",
+            ),
+            (
+                PitchClass::new(Letter::B, Accidental::Natural),
+                "B",
+                "H",
+                "Si",
+                "ロ",
+                "シ",
+            ),
+        ] {
+            for (format, s) in [
+                (PitchClassFormat::Letter, letter),
+                (PitchClassFormat::German, german),
+                (PitchClassFormat::Solfege, solfege),
+                (PitchClassFormat::Japanese, japanese),
+                (PitchClassFormat::KatakanaSolfege, katakana_solfege),
+            ] {
+                assert_eq!(Ok(pitch_class), format.parse(s, &ParseOptions::default()));
+                assert_eq!(s, pitch_class.notation(format).to_string());
+            }
+        }
+
+        let d = PitchClass::new(Letter::D, Accidental::Natural);
+        assert_eq!(
+            "Ré",
+            d.notation(PitchClassFormat::Solfege)
+                .solfege_variant(SolfegeVariant::French)
+                .to_string(),
+        );
+        assert_eq!(
+            Ok(d),
+            PitchClassFormat::Solfege.parse("Ré", &ParseOptions::default()),
+        );
+        assert_eq!(
+            "F♯",
+            PitchClass::new(Letter::F, Accidental::Sharp)
+                .notation(PitchClassFormat::Letter)
+                .accidental_style(AccidentalStyle::Unicode)
+                .to_string(),
+        );
+    }
+
+    #[test]
+    fn test_parse_pitch_class_with_format() {
+        for (s, pitch_class, format) in [
+            (
+                "C#",
+                PitchClass::new(Letter::C, Accidental::Sharp),
+                PitchClassFormat::Letter,
+            ),
+            (
+                "B",
+                PitchClass::new(Letter::B, Accidental::Natural),
+                PitchClassFormat::Letter,
+            ),
+            (
+                "Cis",
+                PitchClass::new(Letter::C, Accidental::Sharp),
+                PitchClassFormat::German,
+            ),
+            (
+                "H",
+                PitchClass::new(Letter::B, Accidental::Natural),
+                PitchClassFormat::German,
+            ),
+            (
+                "嬰ヘ",
+                PitchClass::new(Letter::F, Accidental::Sharp),
+                PitchClassFormat::Japanese,
+            ),
+            (
+                "ファ#",
+                PitchClass::new(Letter::F, Accidental::Sharp),
+                PitchClassFormat::KatakanaSolfege,
+            ),
+            (
+                "Sol♯",
+                PitchClass::new(Letter::G, Accidental::Sharp),
+                PitchClassFormat::Solfege,
+            ),
+            (
+                "Ｆ＃",
+                PitchClass::new(Letter::F, Accidental::Sharp),
+                PitchClassFormat::Letter,
+            ),
+        ] {
+            assert_eq!(
+                Ok(PitchClassWithFormat {
+                    pitch_class,
+                    format,
+                }),
+                s.parse(),
+            );
+        }
+
+        for s in ["invalid", "C4", "c'", "mid2C", "So#", "cis"] {
+            assert_eq!(Err(ParsePitchClassError), s.parse::<PitchClassWithFormat>());
+        }
+
+        assert_eq!(
+            vec![
+                PitchClassWithFormat {
+                    pitch_class: PitchClass::new(Letter::B, Accidental::Natural),
+                    format: PitchClassFormat::Letter,
+                },
+                PitchClassWithFormat {
+                    pitch_class: PitchClass::new(Letter::B, Accidental::Flat),
+                    format: PitchClassFormat::German,
+                },
+            ],
+            PitchClassWithFormat::parse_all("B", &ParseOptions::default()),
+        );
+        assert_eq!(
+            vec![PitchClassWithFormat {
+                pitch_class: PitchClass::new(Letter::B, Accidental::Natural),
+                format: PitchClassFormat::German,
+            }],
+            PitchClassWithFormat::parse_all("H", &ParseOptions::default()),
+        );
+        assert!(PitchClassWithFormat::parse_all("C4", &ParseOptions::default()).is_empty());
+
+        let options = ParseOptions {
+            lenient: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            Ok(PitchClassWithFormat {
+                pitch_class: PitchClass::new(Letter::C, Accidental::Sharp),
+                format: PitchClassFormat::German,
+            }),
+            PitchClassWithFormat::parse("cis", &options),
+        );
+    }
+}
@@ -0,0 +1,289 @@
+use regex::Regex;
+
+use crate::error::{FormatPitchError, ParsePitchClassError, ParsePitchError};
+
+use super::{
+    fmt_cents,
+    lenient::{self, Recase},
+    parse_cents, AccidentalStyle, FormatOptions, Letter, MiddleC, ParseOptions, Pitch, PitchClass,
+    PitchClassFormat, PitchNotation, ACCIDENTAL_PATTERN, CENTS_PATTERN,
+};
+
+/// Spelling of the fixed-do syllables. French writes `Ré`; Spanish spells
+/// them like Italian.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SolfegeVariant {
+    #[default]
+    Italian,
+    French,
+}
+
+impl Pitch {
+    pub fn solfege_notation(&self) -> SolfegeNotation<'_> {
+        SolfegeNotation {
+            pitch: self,
+            accidental_style: AccidentalStyle::default(),
+            middle_c: MiddleC::default(),
+            variant: SolfegeVariant::default(),
+        }
+    }
+}
+
+pub(super) fn solfege_syllable(letter: Letter, variant: SolfegeVariant) -> &'static str {
+    match (letter, variant) {
+        (Letter::C, _) => "Do",
+        (Letter::D, SolfegeVariant::French) => "Ré",
+        (Letter::D, _) => "Re",
+        (Letter::E, _) => "Mi",
+        (Letter::F, _) => "Fa",
+        (Letter::G, _) => "Sol",
+        (Letter::A, _) => "La",
+        (Letter::B, _) => "Si",
+    }
+}
+
+pub(super) fn parse_solfege_syllable(s: &str) -> Result<Letter, ParsePitchClassError> {
+    let letter = match s {
+        "Do" | "Ut" => Letter::C,
+        "Re" | "Ré" => Letter::D,
+        "Mi" => Letter::E,
+        "Fa" => Letter::F,
+        "Sol" => Letter::G,
+        "La" => Letter::A,
+        "Si" => Letter::B,
+        _ => return Err(ParsePitchClassError),
+    };
+
+    Ok(letter)
+}
+
+fn parse_solfege_notation(s: &str, options: &ParseOptions) -> Result<Pitch, ParsePitchError> {
+    let Some(caps) = Regex::new(&format!(
+        r"^(?<syllable>Do|Ut|Re|Ré|Mi|Fa|Sol|La|Si)(?<accidental>{ACCIDENTAL_PATTERN})?(?<octave>0|(-?[1-9]\d*))(?<cents>{CENTS_PATTERN})?$"
+    ))
+    .unwrap()
+    .captures(s) else {
+        return Err(ParsePitchError);
+    };
+
+    let letter = parse_solfege_syllable(caps.name("syllable").unwrap().as_str())?;
+    let pitch_class: PitchClass = format!(
+        "{letter}{}",
+        caps.name("accidental").map_or("", |m| m.as_str()),
+    )
+    .parse()?;
+    let octave =
+        caps.name("octave").unwrap().as_str().parse::<i32>()? + options.middle_c.octave_offset();
+
+    let octave = Pitch::checked_octave(octave)?;
+
+    Ok(Pitch {
+        octave,
+        pitch_class,
+        cents: parse_cents(caps.name("cents").map(|m| m.as_str()))?,
+    })
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SolfegeFormat;
+
+impl PitchNotation for SolfegeFormat {
+    fn name(&self) -> &'static str {
+        "solfege"
+    }
+
+    fn priority(&self) -> i32 {
+        80
+    }
+
+    fn parse(&self, s: &str, options: &ParseOptions) -> Result<Pitch, ParsePitchError> {
+        parse_solfege_notation(&lenient::prepare(s, options, Recase::Capitalize), options)
+    }
+
+    fn format(&self, pitch: &Pitch, options: &FormatOptions) -> Result<String, FormatPitchError> {
+        Ok(pitch
+            .solfege_notation()
+            .accidental_style(options.accidental_style)
+            .middle_c(options.middle_c)
+            .variant(options.solfege_variant)
+            .to_string())
+    }
+
+    fn parse_pitch_class(
+        &self,
+        s: &str,
+        options: &ParseOptions,
+    ) -> Result<PitchClass, ParsePitchClassError> {
+        PitchClassFormat::Solfege.parse_normalized(s, options)
+    }
+
+    fn format_pitch_class(
+        &self,
+        pitch_class: &PitchClass,
+        options: &FormatOptions,
+    ) -> Result<String, FormatPitchError> {
+        Ok(PitchClassFormat::Solfege.format(pitch_class, options))
+    }
+}
+
+/// Fixed-do solfège with octave numbers, e.g. `Do4` or `Sol#3`. The
+/// Franco-Belgian numbering, where middle C is `Do3`, is [`MiddleC::C3`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolfegeNotation<'a> {
+    pitch: &'a Pitch,
+    accidental_style: AccidentalStyle,
+    middle_c: MiddleC,
+    variant: SolfegeVariant,
+}
+
+impl SolfegeNotation<'_> {
+    pub fn accidental_style(mut self, accidental_style: AccidentalStyle) -> Self {
+        self.accidental_style = accidental_style;
+        self
+    }
+
+    pub fn middle_c(mut self, middle_c: MiddleC) -> Self {
+        self.middle_c = middle_c;
+        self
+    }
+
+    pub fn variant(mut self, variant: SolfegeVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+}
+
+impl std::fmt::Display for SolfegeNotation<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let pitch_class = &self.pitch.pitch_class;
+
+        write!(
+            f,
+            "{}{}{}",
+            solfege_syllable(pitch_class.letter, self.variant),
+            pitch_class.accidental.symbol(self.accidental_style),
+            self.pitch.octave as i32 - self.middle_c.octave_offset(),
+        )?;
+
+        fmt_cents(self.pitch.cents, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pitch::Accidental;
+
+    #[test]
+    fn test_solfege_notation() {
+        for (octave, pitch_class, italian, french, franco_belgian) in [
+            (
+                -1,
+                PitchClass::new(Letter::C, Accidental::Natural),
+                "Do-1",
+                "Do-1",
+                "Do-2",
+            ),
+            (
+                2,
+                PitchClass::new(Letter::B, Accidental::Flat),
+                "Sib2",
+                "Sib2",
+                "Sib1",
+            ),
+            (
+                3,
+                PitchClass::new(Letter::G, Accidental::Sharp),
+                "Sol#3",
+                "Sol#3",
+                "Sol#2",
+            ),
+            (
+                4,
+                PitchClass::new(Letter::C, Accidental::Natural),
+                "Do4",
+                "Do4",
+                "Do3",
+            ),
+            (
+                4,
+                PitchClass::new(Letter::D, Accidental::Natural),
+                "Re4",
+                "Ré4",
+                "Ré3",
+            ),
+            (
+                4,
+                PitchClass::new(Letter::E, Accidental::Flat),
+                "Mib4",
+                "Mib4",
+                "Mib3",
+            ),
+            (
+                5,
+                PitchClass::new(Letter::F, Accidental::DoubleSharp),
+                "Fa##5",
+                "Fa##5",
+                "Fa##4",
+            ),
+            (
+                5,
+                PitchClass::new(Letter::A, Accidental::Natural),
+                "La5",
+                "La5",
+                "La4",
+            ),
+        ] {
+            let pitch = Pitch {
+                octave,
+                pitch_class,
+                cents: 0,
+            };
+            let franco_belgian_options = ParseOptions {
+                middle_c: MiddleC::C3,
+                ..Default::default()
+            };
+
+            assert_eq!(
+                Ok(pitch.clone()),
+                parse_solfege_notation(italian, &ParseOptions::default()),
+            );
+            assert_eq!(
+                Ok(pitch.clone()),
+                parse_solfege_notation(french, &ParseOptions::default()),
+            );
+            assert_eq!(
+                Ok(pitch.clone()),
+                parse_solfege_notation(franco_belgian, &franco_belgian_options),
+            );
+            assert_eq!(italian, pitch.solfege_notation().to_string());
+            assert_eq!(
+                french,
+                pitch
+                    .solfege_notation()
+                    .variant(SolfegeVariant::French)
+                    .to_string(),
+            );
+            assert_eq!(
+                franco_belgian,
+                pitch
+                    .solfege_notation()
+                    .variant(SolfegeVariant::French)
+                    .middle_c(MiddleC::C3)
+                    .to_string(),
+            );
+        }
+
+        assert_eq!(
+            parse_solfege_notation("Do4", &ParseOptions::default()),
+            parse_solfege_notation("Ut4", &ParseOptions::default()),
+        );
+
+        for s in ["invalid", "Do", "do4", "So4", "Ti4", "Do256"] {
+            assert_eq!(
+                Err(ParsePitchError),
+                parse_solfege_notation(s, &ParseOptions::default()),
+            );
+        }
+    }
+}
@@ -0,0 +1,258 @@
+use regex::Regex;
+
+use crate::error::{FormatPitchError, ParsePitchError, PitchOutOfRangeError};
+
+use super::{
+    lenient::{self, Recase},
+    FormatOptions, MiddleC, ParseOptions, Pitch, PitchNotation,
+};
+
+/// Tracker cell for a row without a note.
+pub const TRACKER_EMPTY_NOTE: &str = "---";
+
+impl Pitch {
+    /// Fails when the octave needs more than one digit, which does not fit
+    /// in a three-character tracker cell.
+    pub fn tracker_notation(&self) -> Result<TrackerNotation<'_>, PitchOutOfRangeError> {
+        let notation = TrackerNotation {
+            pitch: self,
+            middle_c: MiddleC::default(),
+        };
+        notation.written_pitch()?;
+
+        Ok(notation)
+    }
+}
+
+/// Parses a tracker note cell such as `C-4` or `F#3`. An empty cell (`---`)
+/// yields `None`.
+pub fn parse_tracker_note(
+    s: &str,
+    options: &ParseOptions,
+) -> Result<Option<Pitch>, ParsePitchError> {
+    if s == TRACKER_EMPTY_NOTE {
+        return Ok(None);
+    }
+
+    let Some(caps) = Regex::new(r"^(?<letter>[A-G])(?<accidental>[-#])(?<octave>\d)$")
+        .unwrap()
+        .captures(s)
+    else {
+        return Err(ParsePitchError);
+    };
+
+    let letter = caps.name("letter").unwrap().as_str();
+    let accidental = match caps.name("accidental").unwrap().as_str() {
+        "-" => "",
+        accidental => accidental,
+    };
+    let pitch_class = format!("{letter}{accidental}").parse()?;
+    let octave =
+        caps.name("octave").unwrap().as_str().parse::<i32>()? + options.middle_c.octave_offset();
+
+    let octave = Pitch::checked_octave(octave)?;
+
+    Ok(Some(Pitch {
+        octave,
+        pitch_class,
+        cents: 0,
+    }))
+}
+
+fn parse_tracker_notation(s: &str, options: &ParseOptions) -> Result<Pitch, ParsePitchError> {
+    parse_tracker_note(s, options)?.ok_or(ParsePitchError)
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TrackerFormat;
+
+impl PitchNotation for TrackerFormat {
+    fn name(&self) -> &'static str {
+        "tracker"
+    }
+
+    fn priority(&self) -> i32 {
+        90
+    }
+
+    fn parse(&self, s: &str, options: &ParseOptions) -> Result<Pitch, ParsePitchError> {
+        parse_tracker_notation(&lenient::prepare(s, options, Recase::Uppercase), options)
+    }
+
+    fn format(&self, pitch: &Pitch, options: &FormatOptions) -> Result<String, FormatPitchError> {
+        Ok(pitch
+            .tracker_notation()?
+            .middle_c(options.middle_c)?
+            .to_string())
+    }
+}
+
+/// Three-character tracker note such as `C-4` or `F#3`. Trackers only spell
+/// sharps, so flats and double accidentals are written as their sharp
+/// equivalent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrackerNotation<'a> {
+    pitch: &'a Pitch,
+    middle_c: MiddleC,
+}
+
+impl TrackerNotation<'_> {
+    /// Fails when the octave no longer fits in a single digit.
+    pub fn middle_c(mut self, middle_c: MiddleC) -> Result<Self, PitchOutOfRangeError> {
+        self.middle_c = middle_c;
+        self.written_pitch()?;
+
+        Ok(self)
+    }
+
+    /// The pitch respelled with sharps and its written octave digit.
+    fn written_pitch(&self) -> Result<(Pitch, i32), PitchOutOfRangeError> {
+        let pitch = Pitch::from_semitones(self.pitch.semitones())?;
+        let octave = pitch.octave as i32 - self.middle_c.octave_offset();
+
+        if !(0..=9).contains(&octave) {
+            return Err(PitchOutOfRangeError);
+        }
+
+        Ok((pitch, octave))
+    }
+}
+
+impl std::fmt::Display for TrackerNotation<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (pitch, octave) = self.written_pitch().map_err(|_| std::fmt::Error)?;
+        let pitch_class = &pitch.pitch_class;
+
+        write!(
+            f,
+            "{}{}{octave}",
+            pitch_class.letter,
+            match pitch_class.accidental.as_str() {
+                "" => "-",
+                accidental => accidental,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pitch::{Accidental, Letter, PitchClass};
+
+    #[test]
+    fn test_tracker_notation() {
+        for (octave, pitch_class, s, impulse_tracker) in [
+            (
+                0,
+                PitchClass::new(Letter::C, Accidental::Natural),
+                "C-0",
+                "C-1",
+            ),
+            (
+                3,
+                PitchClass::new(Letter::F, Accidental::Sharp),
+                "F#3",
+                "F#4",
+            ),
+            (
+                4,
+                PitchClass::new(Letter::C, Accidental::Natural),
+                "C-4",
+                "C-5",
+            ),
+            (
+                4,
+                PitchClass::new(Letter::A, Accidental::Sharp),
+                "A#4",
+                "A#5",
+            ),
+            (
+                8,
+                PitchClass::new(Letter::B, Accidental::Natural),
+                "B-8",
+                "B-9",
+            ),
+        ] {
+            let pitch = Pitch {
+                octave,
+                pitch_class,
+                cents: 0,
+            };
+            let impulse_tracker_options = ParseOptions {
+                middle_c: MiddleC::C5,
+                ..Default::default()
+            };
+
+            assert_eq!(
+                Ok(pitch.clone()),
+                parse_tracker_notation(s, &ParseOptions::default()),
+            );
+            assert_eq!(
+                Ok(pitch.clone()),
+                parse_tracker_notation(impulse_tracker, &impulse_tracker_options),
+            );
+            assert_eq!(s, pitch.tracker_notation().unwrap().to_string());
+            assert_eq!(
+                impulse_tracker,
+                pitch
+                    .tracker_notation()
+                    .and_then(|notation| notation.middle_c(MiddleC::C5))
+                    .unwrap()
+                    .to_string(),
+            );
+        }
+
+        assert_eq!(
+            Ok(None),
+            parse_tracker_note(TRACKER_EMPTY_NOTE, &ParseOptions::default()),
+        );
+
+        for s in ["invalid", "---", "C4", "C-10", "Db4", "C-4 ", "c-4", "E#-1"] {
+            assert_eq!(
+                Err(ParsePitchError),
+                parse_tracker_notation(s, &ParseOptions::default()),
+            );
+        }
+    }
+
+    #[test]
+    fn test_tracker_notation_respelling() {
+        for (octave, pitch_class, cents, s) in [
+            (4, PitchClass::new(Letter::D, Accidental::Flat), 0, "C#4"),
+            (4, PitchClass::new(Letter::E, Accidental::Sharp), 0, "F-4"),
+            (3, PitchClass::new(Letter::B, Accidental::Sharp), 0, "C-4"),
+            (4, PitchClass::new(Letter::C, Accidental::Flat), 0, "B-3"),
+            (
+                4,
+                PitchClass::new(Letter::G, Accidental::DoubleSharp),
+                0,
+                "A-4",
+            ),
+            (
+                4,
+                PitchClass::new(Letter::A, Accidental::Natural),
+                -30,
+                "A-4",
+            ),
+        ] {
+            let pitch = Pitch {
+                octave,
+                pitch_class,
+                cents,
+            };
+
+            assert_eq!(s, pitch.tracker_notation().unwrap().to_string());
+        }
+
+        for octave in [-1, 10] {
+            let pitch = Pitch {
+                octave,
+                pitch_class: PitchClass::new(Letter::C, Accidental::Natural),
+                cents: 0,
+            };
+
+            assert_eq!(Err(PitchOutOfRangeError), pitch.tracker_notation());
+        }
+    }
+}
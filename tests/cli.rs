@@ -0,0 +1,166 @@
+use std::process::{Command, Output};
+
+fn pitchconv(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pitchconv"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+fn stderr(output: &Output) -> &str {
+    std::str::from_utf8(&output.stderr).unwrap()
+}
+
+#[test]
+fn test_convert() {
+    for (args, expected) in [
+        (&["C4"][..], "mid2C\n"),
+        (&["--to", "midi", "C4"], "60\n"),
+        (&["--to", "piano-key", "A0"], "1\n"),
+        (&["--to", "tracker", "C#4"], "C#4\n"),
+        (&["--to", "helmholtz", "C5"], "c''\n"),
+        (
+            &["--to", "helmholtz", "--helmholtz-style", "indices", "C5"],
+            "c²\n",
+        ),
+        (&["C7"], "hihihiC\n"),
+        (&["--alternative-style", "numeric", "C7"], "hi3C\n"),
+    ] {
+        let output = pitchconv(args);
+
+        assert!(output.status.success(), "{args:?}: {}", stderr(&output));
+        assert_eq!(expected, stdout(&output));
+    }
+}
+
+#[test]
+fn test_out_of_range_output() {
+    for (args, expected) in [
+        (
+            ["--to", "piano-key", "C0"],
+            "error: C0 is out of range for piano-key",
+        ),
+        (
+            ["--to", "midi", "C10"],
+            "error: C10 is out of range for midi",
+        ),
+        (
+            ["--to", "tracker", "C10"],
+            "error: C10 is out of range for tracker",
+        ),
+        (
+            ["--to", "tracker", "C#-1"],
+            "error: C#-1 is out of range for tracker",
+        ),
+    ] {
+        let output = pitchconv(&args);
+
+        assert_eq!(Some(1), output.status.code(), "{args:?}");
+        assert_eq!("", stdout(&output));
+        assert!(stderr(&output).contains(expected), "{}", stderr(&output));
+    }
+}
+
+#[test]
+fn test_not_a_pitch() {
+    for (args, expected) in [
+        (
+            &["--from", "scientific", "foo"][..],
+            "error: \"foo\" is not a pitch in the scientific format",
+        ),
+        (
+            &["--from", "alternative", "low258A"],
+            "error: \"low258A\" is not a pitch in the alternative format",
+        ),
+        (
+            &["foo"],
+            "error: \"foo\" is not a pitch in any known format",
+        ),
+    ] {
+        let output = pitchconv(args);
+
+        assert_eq!(Some(1), output.status.code(), "{args:?}");
+        assert_eq!("", stdout(&output));
+        assert!(stderr(&output).contains(expected), "{}", stderr(&output));
+    }
+}
+
+#[test]
+fn test_pitch_class_without_octave_form() {
+    for (args, expected) in [
+        (
+            ["--to", "midi", "C#"],
+            "error: --to midi needs an octave, but \"C#\" has none",
+        ),
+        (
+            ["--to", "alternative", "Db"],
+            "error: --to alternative needs an octave, but \"Db\" has none",
+        ),
+    ] {
+        let output = pitchconv(&args);
+
+        assert_eq!(Some(1), output.status.code(), "{args:?}");
+        assert_eq!("", stdout(&output));
+        assert!(stderr(&output).contains(expected), "{}", stderr(&output));
+    }
+
+    let output = pitchconv(&["--to", "german", "Db"]);
+    assert!(output.status.success());
+    assert_eq!("Des\n", stdout(&output));
+}
+
+#[test]
+fn test_ambiguous_input() {
+    for (input, readings, first) in [
+        ("60", "midi (C4), piano-key (G#5)", "midi"),
+        ("B3", "scientific (B3), german (Bb3)", "scientific"),
+        (
+            "B",
+            "scientific (B), helmholtz (B2), abc (B4), german (Bb)",
+            "scientific",
+        ),
+        (
+            "C",
+            "scientific (C), helmholtz (C2), abc (C4), german (C)",
+            "scientific",
+        ),
+    ] {
+        let output = pitchconv(&[input]);
+        assert!(output.status.success());
+        assert!(
+            stderr(&output).contains(&format!(
+                "warning: \"{input}\" is ambiguous: {readings}; reading it as {first}"
+            )),
+            "{}",
+            stderr(&output),
+        );
+
+        let output = pitchconv(&["--no-guess", input]);
+        assert_eq!(Some(1), output.status.code());
+        assert_eq!("", stdout(&output));
+        assert!(stderr(&output).contains(&format!("error: \"{input}\" is ambiguous: {readings}")));
+    }
+
+    for (args, expected) in [
+        (&["--prefer", "german", "B"][..], "Bb\n"),
+        (
+            &["--prefer", "piano-key", "--to", "scientific", "60"],
+            "G#5\n",
+        ),
+        (&["--from", "german", "B"], "Bb\n"),
+        (&["--to", "scientific", "8.00"], "C4\n"),
+        (&["H"], "B\n"),
+        (&["--prefer", "abc", "C"], "C4\n"),
+        (&["--from-middle-c", "c3", "--to", "midi", "C3"], "60\n"),
+    ] {
+        let output = pitchconv(args);
+
+        assert!(output.status.success(), "{args:?}");
+        assert_eq!(expected, stdout(&output));
+        assert_eq!("", stderr(&output), "{args:?}");
+    }
+}